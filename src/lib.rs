@@ -12,6 +12,12 @@
 //!
 //! - Crate is in the "work in progress" state, so the public API may change in the future. Feel free to contribute!
 //!
+//! # Features
+//!
+//! - `rand` - enables the random generation methods of [`TileMap<T>`], using the `rand` crate
+//! - `async` - enables the asynchronous drawing methods of [`TileMap<T>`], using the `tokio` crate
+//! - `ron` - enables the `RON` serialization methods of [`TileMap<T>`], using the `ron` and `serde` crates
+//!
 //! # Examples
 //!
 //! TileMap for custom data type:
@@ -58,18 +64,28 @@
 //! For more documentation about the `Grid`, `GridMap` and `Cell` types, visit https://crates.io/crates/grid-math
 
 use crossterm::{
-    execute,
-    style::{Print, PrintStyledContent, StyledContent},
+    Command,
+    cursor::MoveTo,
+    execute, queue,
+    style::{
+        Attribute, Color, ContentStyle, Print, PrintStyledContent, ResetColor, StyledContent,
+        Stylize,
+    },
+    terminal,
 };
 use grid_math::{Cell, Grid, GridMap};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet, VecDeque},
     convert::From,
-    fmt::Display,
+    error::Error,
+    fmt::{self, Display},
     io,
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Index, IndexMut},
 };
 
+/// Number of terminal columns, that every tab of the `left_indent` is expected to take
+const TAB_WIDTH: u16 = 8;
+
 /// `Tile` allows to represent any other data type as `tile`,
 /// or more specifically as `StyledContent<&'static str>`
 ///
@@ -99,9 +115,113 @@ use std::{
 ///
 /// let hero = Entity::Hero;
 /// assert_eq!(hero.tile(), "[&]".green().bold());
+/// assert_eq!(hero.symbol(), "[&]");
 /// ```
 pub trait Tile {
     fn tile(&self) -> StyledContent<&'static str>;
+
+    /// Returns the raw symbol of the `tile`, without any style
+    ///
+    fn symbol(&self) -> &'static str {
+        self.tile().content()
+    }
+}
+
+/// `TileLegend` allows to describe what the `tile` of some data type means,
+/// by returning the `(symbol, description)` pair, used by the `TileMap::draw_legend` method
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::TileLegend;
+///
+/// enum Entity {
+///     Enemy,
+///     Hero,
+/// }
+///
+/// impl TileLegend for Entity {
+///     fn legend_entry(&self) -> (&'static str, &'static str) {
+///         match self {
+///             Self::Hero => ("[&]", "hero"),
+///             Self::Enemy => ("[@]", "enemy"),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Entity::Hero.legend_entry(), ("[&]", "hero"));
+/// ```
+pub trait TileLegend {
+    fn legend_entry(&self) -> (&'static str, &'static str);
+}
+
+/// `TileCodec` allows to encode any other data type as a short text code, and decode it back,
+/// used by the `TileMap::to_csv` and `TileMap::from_csv` methods
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::TileCodec;
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Terrain {
+///     Wall,
+///     Floor,
+/// }
+///
+/// impl TileCodec for Terrain {
+///     fn code(&self) -> &str {
+///         match self {
+///             Self::Wall => "#",
+///             Self::Floor => ".",
+///         }
+///     }
+///
+///     fn from_code(s: &str) -> Option<Self> {
+///         match s {
+///             "#" => Some(Self::Wall),
+///             "." => Some(Self::Floor),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Terrain::from_code(Terrain::Wall.code()), Some(Terrain::Wall));
+/// ```
+pub trait TileCodec {
+    fn code(&self) -> &str;
+    fn from_code(s: &str) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+/// `TileCategory` allows to group the tiles of some data type into the named categories,
+/// used by the `TileMap::print_category_statistics` method
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::TileCategory;
+///
+/// enum Entity {
+///     Goblin,
+///     Orc,
+///     Floor,
+/// }
+///
+/// impl TileCategory for Entity {
+///     fn category_name(&self) -> &'static str {
+///         match self {
+///             Self::Goblin | Self::Orc => "enemy",
+///             Self::Floor => "floor",
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Entity::Orc.category_name(), "enemy");
+/// ```
+pub trait TileCategory {
+    fn category_name(&self) -> &'static str;
 }
 
 /// `Formatting` represents instructions for `TileMap<T>` on how to draw tilemap to the terminal
@@ -110,7 +230,11 @@ pub trait Tile {
 /// `tile_spacing` - number of spaces between every tile, defaults to 1
 /// `top_indent` - number of newlines to insert before drawing the tilemap, defaults to 3
 /// `left_indent` - number of tabs to insert at the start of every row, defaults to 1
+/// `right_indent` - number of spaces to insert at the end of every row, defaults to 0
 /// `bottom_indent` - number of newlines to insert after drawing the tilemap, defaults to 2
+/// `tile_separator` - styled separator to insert between every two tiles of the row, after the spaces, defaults to `None`
+/// `border_tiles` - whether to insert the `tile_separator` at the start and the end of every row too, defaults to `false`
+/// `reset_after_each_tile` - whether to reset the terminal colors after every drawn tile, defaults to `false`
 ///
 /// # Examples
 ///
@@ -122,7 +246,11 @@ pub trait Tile {
 /// assert_eq!(f.tile_spacing, 1);
 /// assert_eq!(f.top_indent, 3);
 /// assert_eq!(f.left_indent, 1);
+/// assert_eq!(f.right_indent, 0);
 /// assert_eq!(f.bottom_indent, 2);
+/// assert_eq!(f.tile_separator, None);
+/// assert!(!f.border_tiles);
+/// assert!(!f.reset_after_each_tile);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Formatting {
@@ -130,7 +258,11 @@ pub struct Formatting {
     pub tile_spacing: u8,
     pub top_indent: u8,
     pub left_indent: u8,
+    pub right_indent: u8,
     pub bottom_indent: u8,
+    pub tile_separator: Option<StyledContent<&'static str>>,
+    pub border_tiles: bool,
+    pub reset_after_each_tile: bool,
 }
 
 /// Implements default values for `Formatting`
@@ -142,300 +274,8581 @@ impl Default for Formatting {
             tile_spacing: 1,
             top_indent: 3,
             left_indent: 1,
+            right_indent: 0,
             bottom_indent: 2,
+            tile_separator: None,
+            border_tiles: false,
+            reset_after_each_tile: false,
         }
     }
 }
 
-/// `TileMap<T>`, represents a tilemap over the type `T`, where `T` is `Tile` + `Default`
+impl Formatting {
+    /// Returns the `tile_separator`, if it should be drawn at the start and the end of every row
+    fn border_separator(&self) -> Option<StyledContent<&'static str>> {
+        self.tile_separator.filter(|_| self.border_tiles)
+    }
+
+    /// Returns the width of the `tile_separator` in terminal columns, or 0 if there is no separator
+    fn separator_width(&self) -> u16 {
+        self.tile_separator
+            .map_or(0, |separator| separator.content().chars().count() as u16)
+    }
+}
+
+/// `Dimensions` represents the `width` and the `depth` of the `TileMap<T>`
 ///
-/// `TileMap<T>` is based on the `GridMap<V>` from the `grid-math` crate,
-/// and implements the `Deref` and the `DerefMut` traits to deref to the inner `GridMap<T>`
+/// `Dimensions` don't hold the start of the inner `Grid`, which may be not at (0,0),
+/// so use `cell.within(map.grid())` to check if the cell is within the map
 ///
 /// # Examples
 ///
 /// ```
-/// use cli_tilemap::{Tile, TileMap, Formatting};
+/// use cli_tilemap::{Dimensions, Tile, TileMap};
 /// use crossterm::style::{Stylize, StyledContent};
-/// use grid_math::Cell;
-/// use std::io::stdout;
-///
+/// use grid_math::{Cell, Grid};
 ///
-/// #[derive(Default, Debug)]
-/// enum Entity {
-///     Enemy,
-///     Hero,
-///     #[default]
-///     Air,
-/// }
+/// #[derive(Default)]
+/// struct Empty;
 ///
-/// impl Tile for Entity {
+/// impl Tile for Empty {
 ///     fn tile(&self) -> StyledContent<&'static str> {
-///         match self {
-///             Self::Air => "[-]".dark_grey().bold(),
-///             Self::Hero => "[&]".green().bold(),
-///             Self::Enemy => "[@]".red().bold(),
-///         }
+///         "[-]".dark_grey().bold()
 ///     }
 /// }
 ///
-/// // new 5x5 tilemap:
-/// let mut map: TileMap<Entity> = TileMap::new(5, 5);
-/// // insert entities:
-/// map.insert(Cell::new(3, 3), Entity::Enemy);
-/// map.insert(Cell::new(1, 0), Entity::Hero);
-/// // draw map to the raw stdout:
-/// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
-/// // change row and tile spacing:
-/// map.formatting.row_spacing = 2;
-/// map.formatting.tile_spacing = 4;
-/// // format as a string and print:
-/// let map_string = map.to_string();
-/// println!("{map_string}");
+/// let dimensions = Dimensions { width: 4, depth: 3 };
+/// assert_eq!(dimensions.area(), 12);
+/// let map: TileMap<Empty> = TileMap::from(Grid::from((Cell::new(2, 2), Cell::new(6, 6))));
+/// assert_eq!(map.dimensions(), Dimensions { width: 5, depth: 5 });
+/// assert!(Cell::new(5, 5).within(map.grid()));
 /// ```
-#[derive(Debug, Clone)]
-pub struct TileMap<T>
-where
-    T: Tile + Default,
-{
-    pub formatting: Formatting,
-    gridmap: GridMap<T>,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dimensions {
+    pub width: u8,
+    pub depth: u8,
 }
 
-impl<T> TileMap<T>
-where
-    T: Tile + Default,
-{
-    /// Creates new `TileMap<T>` with the empty inner `GridMap<T>` of specified size,
-    /// and with the defult `Formatting`
-    ///
-    /// For more info, visit `grid-math` crate docs
+impl Dimensions {
+    /// Calculates the number of cells within the `Dimensions`
     ///
-    pub fn new(width: u8, depth: u8) -> Self {
-        Self {
-            formatting: Formatting::default(),
-            gridmap: GridMap::new(width, depth),
-        }
+    pub fn area(&self) -> usize {
+        self.width as usize * self.depth as usize
     }
+}
 
-    /// Creates new `TileMap<T>` with the empty inner `GridMap<T>` of specified size,
-    /// and with the given `Formatting`
-    ///
-    /// For more info, visit `grid-math` crate docs
+/// `Viewport` represents the visible rectangle of the `TileMap<T>`, starting at the `top_left` cell,
+/// and spanning `width` columns and `depth` rows
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::Viewport;
+/// use grid_math::Cell;
+///
+/// let viewport = Viewport { top_left: Cell::new(2, 1), width: 4, depth: 3 };
+/// assert_eq!(viewport.dimensions().area(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Viewport {
+    pub top_left: Cell,
+    pub width: u8,
+    pub depth: u8,
+}
+
+impl Viewport {
+    /// Returns the `Dimensions` of the `Viewport`
     ///
-    pub fn formatted(width: u8, depth: u8, formatting: Formatting) -> Self {
-        Self {
-            formatting,
-            gridmap: GridMap::new(width, depth),
+    pub fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            width: self.width,
+            depth: self.depth,
         }
     }
+}
 
-    /// Draws the `TileMap<T>` to the given `stdout`, using the inner `Formatting` rules
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use cli_tilemap::{Tile, TileMap};
-    /// use crossterm::style::{Stylize, StyledContent};
-    /// use std::io::stdout;
-    ///
-    /// #[derive(Default)]
-    /// struct Empty;
-    ///
-    /// impl Tile for Empty {
-    ///     fn tile(&self) -> StyledContent<&'static str> {
-    ///         "[-]".dark_grey().bold()
-    ///     }
-    /// }
+/// Calculates the Manhattan distance between two cells,
+/// which is the sum of the absolute differences of their widths and depths
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::manhattan_distance;
+/// use grid_math::Cell;
+///
+/// assert_eq!(manhattan_distance(Cell::new(1, 1), Cell::new(4, 3)), 5);
+/// ```
+pub fn manhattan_distance(a: Cell, b: Cell) -> u16 {
+    a.global_width().abs_diff(b.global_width()) as u16
+        + a.global_depth().abs_diff(b.global_depth()) as u16
+}
+
+/// Calculates the Chebyshev distance between two cells,
+/// which is the biggest of the absolute differences of their widths and depths
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::chebyshev_distance;
+/// use grid_math::Cell;
+///
+/// assert_eq!(chebyshev_distance(Cell::new(1, 1), Cell::new(4, 3)), 3);
+/// ```
+pub fn chebyshev_distance(a: Cell, b: Cell) -> u16 {
+    a.global_width()
+        .abs_diff(b.global_width())
+        .max(a.global_depth().abs_diff(b.global_depth())) as u16
+}
+
+/// `DistanceMetric` represents the way of measuring the distance between two cells
+///
+/// `Manhattan` - 4-directional distance, see `manhattan_distance`
+/// `Chebyshev` - 8-directional distance, see `chebyshev_distance`
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::DistanceMetric;
+/// use grid_math::Cell;
+///
+/// let (a, b) = (Cell::new(0, 0), Cell::new(2, 2));
+/// assert_eq!(DistanceMetric::Manhattan.distance(a, b), 4);
+/// assert_eq!(DistanceMetric::Chebyshev.distance(a, b), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DistanceMetric {
+    Manhattan,
+    Chebyshev,
+}
+
+impl DistanceMetric {
+    /// Calculates the distance between two cells, using the current metric
     ///
-    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
-    /// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
-    /// ```
-    pub fn draw<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
-        execute!(
-            stdout,
-            Print("\n\r".repeat(self.formatting.top_indent as usize))
-        )?;
-        for row in self.grid().rows() {
-            execute!(
-                stdout,
-                Print("\n\r".repeat(self.formatting.row_spacing as usize)),
-                Print("\t".repeat(self.formatting.left_indent as usize))
-            )?;
-            for cell in row.cells() {
-                execute!(
-                    stdout,
-                    Print(" ".repeat(self.formatting.tile_spacing as usize)),
-                    PrintStyledContent(self.get(&cell).unwrap_or(&T::default()).tile())
-                )?;
-            }
-            execute!(stdout, Print("\n\r"))?;
+    pub fn distance(self, a: Cell, b: Cell) -> u16 {
+        match self {
+            Self::Manhattan => manhattan_distance(a, b),
+            Self::Chebyshev => chebyshev_distance(a, b),
         }
-        execute!(
-            stdout,
-            Print("\n\r".repeat(self.formatting.bottom_indent as usize))
-        )?;
-        Ok(())
     }
 }
 
-impl<T> Display for TileMap<T>
-where
-    T: Tile + Default,
-{
-    /// Implements `fmt` method for the `TileMap<T>` in the same way as the `draw` method works
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use cli_tilemap::{Tile, TileMap};
-    /// use crossterm::style::{Stylize, StyledContent};
-    ///
-    /// #[derive(Default)]
-    /// struct Empty;
-    ///
-    /// impl Tile for Empty {
-    ///     fn tile(&self) -> StyledContent<&'static str> {
-    ///         "[-]".dark_grey().bold()
-    ///     }
-    /// }
-    ///
-    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
-    /// println!("{map}");
-    /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", "\n\r".repeat(self.formatting.top_indent as usize))?;
-        for row in self.grid().rows() {
-            write!(f, "{}", "\n\r".repeat(self.formatting.row_spacing as usize))?;
-            write!(f, "{}", "\t".repeat(self.formatting.left_indent as usize))?;
-            for cell in row.cells() {
-                write!(f, "{}", " ".repeat(self.formatting.tile_spacing as usize))?;
-                write!(f, "{}", self.get(&cell).unwrap_or(&T::default()).tile())?;
-            }
-            write!(f, "\n\r")?;
+/// `SymmetryAxis` represents the axis, across which the tiles of the `TileMap<T>` are mirrored
+///
+/// `Horizontal` - mirrors the top and the bottom halves of the map
+/// `Vertical` - mirrors the left and the right halves of the map
+/// `Both` - mirrors across both axes, so every quarter of the map is mirrored to the other three
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymmetryAxis {
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+/// `Axis` represents the axis, along which the tiles of the `TileMap<T>` change
+///
+/// `Horizontal` - along the columns, from left to right
+/// `Vertical` - along the rows, from top to bottom
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+/// `Rotation` represents the clockwise rotation of the cells of the `TileMap<T>` by the multiple of 90 degrees
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    Clockwise90,
+    Clockwise180,
+    Clockwise270,
+}
+
+impl Rotation {
+    /// Returns the given `(width, depth)` offset, rotated clockwise, with the depth growing to the bottom of the map
+    fn rotate(self, (width, depth): (i16, i16)) -> (i16, i16) {
+        match self {
+            Self::Clockwise90 => (-depth, width),
+            Self::Clockwise180 => (-width, -depth),
+            Self::Clockwise270 => (depth, -width),
         }
-        write!(
-            f,
-            "{}",
-            "\n\r".repeat(self.formatting.bottom_indent as usize)
-        )?;
-        Ok(())
     }
 }
 
-impl<T> From<Grid> for TileMap<T>
-where
-    T: Tile + Default,
-{
-    /// Creates new empty `TileMap<T>` from the specified `Grid`
+/// `Direction` represents one of the eight directions on the `TileMap<T>`,
+/// where `North` points to the top of the map, and `East` points to the right
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::Direction;
+///
+/// assert_eq!(Direction::North.offset(), (0, -1));
+/// assert_eq!(Direction::SouthEast.offset(), (1, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// Returns the `(width, depth)` offset of the single step in the current direction
+    ///
+    pub fn offset(self) -> (i16, i16) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+}
+
+/// `BorderStyle` represents the set of box-drawing characters, used to draw the border around the `TileMap<T>`
+///
+/// `Single` - `┌─┐│└┘` characters
+/// `Double` - `╔═╗║╚╝` characters
+/// `Rounded` - `╭─╮│╰╯` characters
+/// `Heavy` - `┏━┓┃┗┛` characters
+/// `Ascii` - `+-+|++` characters
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderStyle {
+    Single,
+    Double,
+    Rounded,
+    Heavy,
+    Ascii,
+}
+
+impl BorderStyle {
+    /// Returns the top left, horizontal, top right, vertical, bottom left and bottom right characters of the style
+    fn chars(self) -> [char; 6] {
+        match self {
+            Self::Single => ['┌', '─', '┐', '│', '└', '┘'],
+            Self::Double => ['╔', '═', '╗', '║', '╚', '╝'],
+            Self::Rounded => ['╭', '─', '╮', '│', '╰', '╯'],
+            Self::Heavy => ['┏', '━', '┓', '┃', '┗', '┛'],
+            Self::Ascii => ['+', '-', '+', '|', '+', '+'],
+        }
+    }
+}
+
+/// `CellUpdate` represents a single changed tile of the drawn `TileMap<T>`, as a crossterm `Command`,
+/// which moves the cursor to the `column` and `row` of the terminal, and prints the `content` there
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::CellUpdate;
+/// use crossterm::{queue, style::Stylize};
+///
+/// let update = CellUpdate { column: 4, row: 2, content: "[#]".white() };
+/// let mut buffer: Vec<u8> = Vec::new();
+/// queue!(buffer, update).expect("should be able to write to the buffer!");
+/// assert!(String::from_utf8_lossy(&buffer).contains("[#]"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellUpdate {
+    pub column: u16,
+    pub row: u16,
+    pub content: StyledContent<&'static str>,
+}
+
+impl Command for CellUpdate {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        MoveTo(self.column, self.row).write_ansi(f)?;
+        PrintStyledContent(self.content).write_ansi(f)
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        MoveTo(self.column, self.row).execute_winapi()?;
+        PrintStyledContent(self.content).execute_winapi()
+    }
+}
+
+/// `DimensionError` represents an error of creating the `TileMap<T>` from data with invalid dimensions
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::DimensionError;
+///
+/// let e = DimensionError::RaggedRows;
+/// assert_eq!(e.to_string(), "rows have different lengths!");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DimensionError {
+    /// Data has no rows, or rows have no tiles
+    Empty,
+    /// Rows have different lengths
+    RaggedRows,
+    /// Width or depth exceeds `u8::MAX`
+    Overflow,
+}
+
+impl Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "can't create tilemap with width < 1 or depth < 1!"),
+            Self::RaggedRows => write!(f, "rows have different lengths!"),
+            Self::Overflow => write!(f, "width or depth exceeds u8::MAX!"),
+        }
+    }
+}
+
+impl Error for DimensionError {}
+
+/// `RowError` represents an error of setting the whole row or column of the `TileMap<T>`
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::RowError;
+///
+/// let e = RowError::LengthMismatch { expected: 5, found: 3 };
+/// assert_eq!(e.to_string(), "expected 5 tiles, found 3!");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowError {
+    /// Index of the row or column is out of bounds
+    OutOfBounds(u8),
+    /// Number of the given tiles doesn't match the length of the row or column
+    LengthMismatch { expected: u8, found: usize },
+}
+
+impl Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds(index) => write!(f, "index {index} is out of bounds!"),
+            Self::LengthMismatch { expected, found } => {
+                write!(f, "expected {expected} tiles, found {found}!")
+            }
+        }
+    }
+}
+
+impl Error for RowError {}
+
+/// `DiffError` represents an error of comparing two `TileMap<T>` with different inner `Grid`s
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::DiffError;
+/// use grid_math::Grid;
+///
+/// let e = DiffError { left: Grid::new(2, 2), right: Grid::new(3, 3) };
+/// assert_eq!(e.to_string(), "can't compare different grids! left:[(0, 0):(1, 1)], right:[(0, 0):(2, 2)]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffError {
+    pub left: Grid,
+    pub right: Grid,
+}
+
+impl Display for DiffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "can't compare different grids! left:{}, right:{}",
+            self.left, self.right
+        )
+    }
+}
+
+impl Error for DiffError {}
+
+/// `MergeError` represents an error of merging the `source` `TileMap<T>` into the `target` one with a different inner `Grid`
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::MergeError;
+/// use grid_math::Grid;
+///
+/// let e = MergeError { target: Grid::new(2, 2), source: Grid::new(3, 3) };
+/// assert_eq!(e.to_string(), "can't merge different grids! target:[(0, 0):(1, 1)], source:[(0, 0):(2, 2)]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeError {
+    pub target: Grid,
+    pub source: Grid,
+}
+
+impl Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "can't merge different grids! target:{}, source:{}",
+            self.target, self.source
+        )
+    }
+}
+
+impl Error for MergeError {}
+
+/// `ZipError` represents an error of zipping two `TileMap`s with inner `Grid`s of different dimensions
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::ZipError;
+/// use grid_math::Grid;
+///
+/// let e = ZipError { left: Grid::new(2, 2), right: Grid::new(3, 3) };
+/// assert_eq!(e.to_string(), "can't zip grids of different dimensions! left:[(0, 0):(1, 1)], right:[(0, 0):(2, 2)]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZipError {
+    pub left: Grid,
+    pub right: Grid,
+}
+
+impl Display for ZipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "can't zip grids of different dimensions! left:{}, right:{}",
+            self.left, self.right
+        )
+    }
+}
+
+impl Error for ZipError {}
+
+/// `ParseError` represents an error of parsing the `TileMap<T>` from the text
+///
+/// Lines and columns are counted from 0
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::ParseError;
+///
+/// let e = ParseError::UnknownCode { line: 1, column: 2, code: String::from("?") };
+/// assert_eq!(e.to_string(), "unknown tile code \"?\" at line 1, column 2!");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Code is not recognized by the `TileCodec::from_code`
+    UnknownCode {
+        line: usize,
+        column: usize,
+        code: String,
+    },
+    /// Code is not within the size of the map
+    OutOfBounds { line: usize, column: usize },
+    /// Size of the map has width < 1 or depth < 1
+    EmptySize,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCode { line, column, code } => {
+                write!(
+                    f,
+                    "unknown tile code {code:?} at line {line}, column {column}!"
+                )
+            }
+            Self::OutOfBounds { line, column } => {
+                write!(f, "line {line}, column {column} is out of bounds!")
+            }
+            Self::EmptySize => write!(f, "can't parse tilemap with width < 1 or depth < 1!"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// `OverflowError` represents an error of growing the `TileMap<T>` over the `u8::MAX` size or coordinates
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::OverflowError;
+///
+/// let e = OverflowError { size: 250, extra: 10 };
+/// assert_eq!(e.to_string(), "can't grow the size of 250 by 10!");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverflowError {
+    pub size: u8,
+    pub extra: u8,
+}
+
+impl Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "can't grow the size of {} by {}!", self.size, self.extra)
+    }
+}
+
+impl Error for OverflowError {}
+
+/// `OutOfBoundsError` represents an error of accessing the `cell`, that is not within the inner `Grid`
+/// of the `TileMap<T>` of `grid_width` x `grid_depth` size
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::OutOfBoundsError;
+/// use grid_math::Cell;
+///
+/// let e = OutOfBoundsError { cell: Cell::new(6, 2), grid_width: 5, grid_depth: 5 };
+/// assert_eq!(e.to_string(), "cell (6, 2) is out of bounds of the 5x5 grid!");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBoundsError {
+    pub cell: Cell,
+    pub grid_width: u8,
+    pub grid_depth: u8,
+}
+
+impl OutOfBoundsError {
+    /// Returns `OutOfBoundsError` if the `cell` is not within the given `grid`
+    fn check(cell: Cell, grid: Grid) -> Result<(), Self> {
+        if cell.within(grid) {
+            Ok(())
+        } else {
+            Err(Self {
+                cell,
+                grid_width: grid.width(),
+                grid_depth: grid.depth(),
+            })
+        }
+    }
+}
+
+impl Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cell {} is out of bounds of the {}x{} grid!",
+            self.cell, self.grid_width, self.grid_depth
+        )
+    }
+}
+
+impl Error for OutOfBoundsError {}
+
+/// `TileError` unifies all errors of the `TileMap<T>` operations,
+/// so they can be propagated with the `?` operator from a single function
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::{OutOfBoundsError, Tile, TileError, TileMap};
+/// use crossterm::style::{Stylize, StyledContent};
+/// use grid_math::Cell;
+///
+/// #[derive(Default)]
+/// struct Empty;
+///
+/// impl Tile for Empty {
+///     fn tile(&self) -> StyledContent<&'static str> {
+///         "[-]".dark_grey().bold()
+///     }
+/// }
+///
+/// fn build() -> Result<TileMap<Empty>, TileError> {
+///     let mut map: TileMap<Empty> = TileMap::new(5, 5);
+///     map.widen(2)?;
+///     map.try_insert(Cell::new(9, 0), Empty)?;
+///     Ok(map)
+/// }
+///
+/// let e = build().unwrap_err();
+/// assert_eq!(
+///     e,
+///     TileError::OutOfBounds(OutOfBoundsError { cell: Cell::new(9, 0), grid_width: 7, grid_depth: 5 })
+/// );
+/// assert_eq!(e.to_string(), "cell (9, 0) is out of bounds of the 7x5 grid!");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TileError {
+    /// Cell is not within the inner `Grid`
+    OutOfBounds(OutOfBoundsError),
+    /// Data has invalid dimensions
+    Dimension(DimensionError),
+    /// Row or column can't be set
+    Row(RowError),
+    /// Maps with different `Grid`s can't be compared
+    Diff(DiffError),
+    /// Maps with different `Grid`s can't be merged
+    Merge(MergeError),
+    /// Maps with `Grid`s of different dimensions can't be zipped
+    Zip(ZipError),
+    /// Text can't be parsed
+    Parse(ParseError),
+    /// Map can't grow over the `u8::MAX` size
+    Overflow(OverflowError),
+    /// Top left corner of the region is below or to the right of the bottom right corner
+    InvertedRegion { top_left: Cell, bottom_right: Cell },
+}
+
+impl Display for TileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds(e) => e.fmt(f),
+            Self::Dimension(e) => e.fmt(f),
+            Self::Row(e) => e.fmt(f),
+            Self::Diff(e) => e.fmt(f),
+            Self::Merge(e) => e.fmt(f),
+            Self::Zip(e) => e.fmt(f),
+            Self::Parse(e) => e.fmt(f),
+            Self::Overflow(e) => e.fmt(f),
+            Self::InvertedRegion {
+                top_left,
+                bottom_right,
+            } => write!(
+                f,
+                "top left {top_left} overflows bottom right {bottom_right}!"
+            ),
+        }
+    }
+}
+
+impl Error for TileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::OutOfBounds(e) => Some(e),
+            Self::Dimension(e) => Some(e),
+            Self::Row(e) => Some(e),
+            Self::Diff(e) => Some(e),
+            Self::Merge(e) => Some(e),
+            Self::Zip(e) => Some(e),
+            Self::Parse(e) => Some(e),
+            Self::Overflow(e) => Some(e),
+            Self::InvertedRegion { .. } => None,
+        }
+    }
+}
+
+impl From<OutOfBoundsError> for TileError {
+    fn from(e: OutOfBoundsError) -> Self {
+        Self::OutOfBounds(e)
+    }
+}
+
+impl From<DimensionError> for TileError {
+    fn from(e: DimensionError) -> Self {
+        Self::Dimension(e)
+    }
+}
+
+impl From<RowError> for TileError {
+    fn from(e: RowError) -> Self {
+        Self::Row(e)
+    }
+}
+
+impl From<DiffError> for TileError {
+    fn from(e: DiffError) -> Self {
+        Self::Diff(e)
+    }
+}
+
+impl From<MergeError> for TileError {
+    fn from(e: MergeError) -> Self {
+        Self::Merge(e)
+    }
+}
+
+impl From<ZipError> for TileError {
+    fn from(e: ZipError) -> Self {
+        Self::Zip(e)
+    }
+}
+
+impl From<ParseError> for TileError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<OverflowError> for TileError {
+    fn from(e: OverflowError) -> Self {
+        Self::Overflow(e)
+    }
+}
+
+/// `TileMap<T>`, represents a tilemap over the type `T`, where `T` is `Tile` + `Default`
+///
+/// `TileMap<T>` is based on the `GridMap<V>` from the `grid-math` crate,
+/// and implements the `Deref` and the `DerefMut` traits to deref to the inner `GridMap<T>`
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::{Tile, TileMap, Formatting};
+/// use crossterm::style::{Stylize, StyledContent};
+/// use grid_math::Cell;
+/// use std::io::stdout;
+///
+///
+/// #[derive(Default, Debug)]
+/// enum Entity {
+///     Enemy,
+///     Hero,
+///     #[default]
+///     Air,
+/// }
+///
+/// impl Tile for Entity {
+///     fn tile(&self) -> StyledContent<&'static str> {
+///         match self {
+///             Self::Air => "[-]".dark_grey().bold(),
+///             Self::Hero => "[&]".green().bold(),
+///             Self::Enemy => "[@]".red().bold(),
+///         }
+///     }
+/// }
+///
+/// // new 5x5 tilemap:
+/// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+/// // insert entities:
+/// map.insert(Cell::new(3, 3), Entity::Enemy);
+/// map.insert(Cell::new(1, 0), Entity::Hero);
+/// // draw map to the raw stdout:
+/// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+/// // change row and tile spacing:
+/// map.formatting.row_spacing = 2;
+/// map.formatting.tile_spacing = 4;
+/// // format as a string and print:
+/// let map_string = map.to_string();
+/// println!("{map_string}");
+/// ```
+#[derive(Clone)]
+pub struct TileMap<T>
+where
+    T: Tile + Default,
+{
+    pub formatting: Formatting,
+    gridmap: GridMap<T>,
+    default: T,
+}
+
+impl<T> TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Creates new `TileMap<T>` with the empty inner `GridMap<T>` of specified size,
+    /// and with the defult `Formatting`
+    ///
+    /// For more info, visit `grid-math` crate docs
+    ///
+    pub fn new(width: u8, depth: u8) -> Self {
+        Self {
+            formatting: Formatting::default(),
+            gridmap: GridMap::new(width, depth),
+            default: T::default(),
+        }
+    }
+
+    /// Creates new `TileMap<T>` with the empty inner `GridMap<T>` of specified size,
+    /// and with the given `Formatting`
+    ///
+    /// For more info, visit `grid-math` crate docs
+    ///
+    pub fn formatted(width: u8, depth: u8, formatting: Formatting) -> Self {
+        Self {
+            formatting,
+            gridmap: GridMap::new(width, depth),
+            default: T::default(),
+        }
+    }
+
+    /// Sets the given `Formatting` for the `TileMap<T>`, and returns it back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Formatting, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let formatting = Formatting {
+    ///     row_spacing: 0,
+    ///     ..Formatting::default()
+    /// };
+    /// let map: TileMap<Empty> = TileMap::new(10, 10).with_formatting(formatting);
+    /// assert_eq!(map.formatting, formatting);
+    /// let map: TileMap<Empty> = TileMap::new(10, 10).with_tile_spacing(2).with_left_indent(0);
+    /// assert_eq!(map.formatting.tile_spacing, 2);
+    /// assert_eq!(map.formatting.left_indent, 0);
+    /// ```
+    pub fn with_formatting(mut self, formatting: Formatting) -> Self {
+        self.formatting = formatting;
+        self
+    }
+
+    /// Sets the `row_spacing` of the inner `Formatting`, number of additional newlines between every row, and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_row_spacing(mut self, row_spacing: u8) -> Self {
+        self.formatting.row_spacing = row_spacing;
+        self
+    }
+
+    /// Sets the `tile_spacing` of the inner `Formatting`, number of spaces between every tile, and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_tile_spacing(mut self, tile_spacing: u8) -> Self {
+        self.formatting.tile_spacing = tile_spacing;
+        self
+    }
+
+    /// Sets the `top_indent` of the inner `Formatting`, number of newlines to insert before drawing the tilemap, and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_top_indent(mut self, top_indent: u8) -> Self {
+        self.formatting.top_indent = top_indent;
+        self
+    }
+
+    /// Sets the `left_indent` of the inner `Formatting`, number of tabs to insert at the start of every row, and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_left_indent(mut self, left_indent: u8) -> Self {
+        self.formatting.left_indent = left_indent;
+        self
+    }
+
+    /// Sets the `right_indent` of the inner `Formatting`, number of spaces to insert at the end of every row, and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_right_indent(mut self, right_indent: u8) -> Self {
+        self.formatting.right_indent = right_indent;
+        self
+    }
+
+    /// Sets the `bottom_indent` of the inner `Formatting`, number of newlines to insert after drawing the tilemap, and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_bottom_indent(mut self, bottom_indent: u8) -> Self {
+        self.formatting.bottom_indent = bottom_indent;
+        self
+    }
+
+    /// Sets the `tile_separator` of the inner `Formatting`, styled separator to insert between every two tiles of the row,
+    /// and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_tile_separator(
+        mut self,
+        tile_separator: Option<StyledContent<&'static str>>,
+    ) -> Self {
+        self.formatting.tile_separator = tile_separator;
+        self
+    }
+
+    /// Sets the `border_tiles` of the inner `Formatting`, whether to insert the `tile_separator`
+    /// at the start and the end of every row too, and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_border_tiles(mut self, border_tiles: bool) -> Self {
+        self.formatting.border_tiles = border_tiles;
+        self
+    }
+
+    /// Sets the `reset_after_each_tile` of the inner `Formatting`, whether to reset the terminal colors
+    /// after every drawn tile, and returns the `TileMap<T>` back
+    ///
+    /// For examples, see the `with_formatting` method
+    ///
+    pub fn with_reset_after_each_tile(mut self, reset_after_each_tile: bool) -> Self {
+        self.formatting.reset_after_each_tile = reset_after_each_tile;
+        self
+    }
+
+    /// Creates new `TileMap<T>` from the 2D `Vec` of tiles, with the width equal to the length of the rows,
+    /// and the depth equal to the number of rows, inserting only the non-default tiles
+    ///
+    /// # Errors
+    /// Returns `DimensionError` if there are no tiles, if the rows have different lengths,
+    /// or if the width or the depth exceeds `u8::MAX`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{DimensionError, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let data = vec![
+    ///     vec![Terrain::Wall, Terrain::Floor, Terrain::Floor],
+    ///     vec![Terrain::Floor, Terrain::Floor, Terrain::Wall],
+    /// ];
+    /// let map = TileMap::from_vec2d(data.clone()).expect("rows should have the same length!");
+    /// assert_eq!(map.grid(), Grid::new(3, 2));
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&Cell::new(2, 1)), Some(&Terrain::Wall));
+    /// assert_eq!(map.to_vec2d(), data);
+    ///
+    /// let ragged = vec![vec![Terrain::Wall], vec![]];
+    /// assert_eq!(TileMap::from_vec2d(ragged).unwrap_err(), DimensionError::RaggedRows);
+    /// ```
+    pub fn from_vec2d(data: Vec<Vec<T>>) -> Result<TileMap<T>, DimensionError>
+    where
+        T: PartialEq,
+    {
+        let width = data.first().map_or(0, |row| row.len());
+        if data.iter().any(|row| row.len() != width) {
+            return Err(DimensionError::RaggedRows);
+        }
+        if width == 0 {
+            return Err(DimensionError::Empty);
+        }
+        let (Ok(width), Ok(depth)) = (u8::try_from(width), u8::try_from(data.len())) else {
+            return Err(DimensionError::Overflow);
+        };
+        let default = T::default();
+        let mut map = TileMap::new(width, depth);
+        for (depth, row) in data.into_iter().enumerate() {
+            for (width, tile) in row.into_iter().enumerate() {
+                if tile != default {
+                    map.insert(Cell::new(width as u8, depth as u8), tile);
+                }
+            }
+        }
+        Ok(map)
+    }
+
+    /// Creates `TileMap<T>` from the existing `HashMap<Cell, T>` and the given `Grid`
+    /// in the same way as the `From<(Grid, HashMap<Cell, T>)>` implementation does,
+    /// but without panicking if the `HashMap<Cell, T>` contains cells that are not within the `Grid`
+    ///
+    /// # Errors
+    /// Returns `OutOfBoundsError` with the first out of bounds cell in the row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{OutOfBoundsError, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let hashmap = HashMap::from([(Cell::new(1, 2), Empty)]);
+    /// let map = TileMap::try_from_hashmap(Grid::new(5, 5), hashmap).expect("cells should be within the grid!");
+    /// assert_eq!(map.get(&Cell::new(1, 2)), Some(&Empty));
+    ///
+    /// let hashmap = HashMap::from([(Cell::new(6, 6), Empty), (Cell::new(7, 1), Empty)]);
+    /// assert_eq!(
+    ///     TileMap::try_from_hashmap(Grid::new(5, 5), hashmap).unwrap_err(),
+    ///     OutOfBoundsError { cell: Cell::new(7, 1), grid_width: 5, grid_depth: 5 }
+    /// );
+    /// ```
+    pub fn try_from_hashmap(
+        grid: Grid,
+        hashmap: HashMap<Cell, T>,
+    ) -> Result<TileMap<T>, OutOfBoundsError> {
+        if let Some(cell) = hashmap
+            .keys()
+            .filter(|cell| !cell.within(grid))
+            .min_by_key(|cell| (cell.global_depth(), cell.global_width()))
+        {
+            OutOfBoundsError::check(*cell, grid)?;
+        }
+        Ok(TileMap::from((grid, hashmap)))
+    }
+
+    /// Returns the 2D `Vec` of the tiles, one `Vec` for every row,
+    /// using `T::default()` for the vacant cells
+    ///
+    /// For examples, see the `from_vec2d` method
+    ///
+    pub fn to_vec2d(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.grid()
+            .rows()
+            .map(|row| {
+                row.cells()
+                    .map(|cell| self.get(&cell).cloned().unwrap_or_default())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Creates new `TileMap<T>` of specified size, calling `f` with the given `rng` for every cell
+    /// in the row-major order, and inserting only the non-default tiles
+    ///
+    /// The same seeded `rng` always generates the same map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use rand::{Rng, SeedableRng, rngs::StdRng};
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Tree,
+    ///     #[default]
+    ///     Grass,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Grass => "[\"]".green(),
+    ///             Self::Tree => "[T]".dark_green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let forest = |_, rng: &mut StdRng| if rng.random_bool(0.3) { Terrain::Tree } else { Terrain::Grass };
+    /// let map = TileMap::generate_with_rng(10, 10, &mut StdRng::seed_from_u64(42), forest);
+    /// let same = TileMap::generate_with_rng(10, 10, &mut StdRng::seed_from_u64(42), forest);
+    /// assert_eq!(map.to_plain_string(), same.to_plain_string());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_with_rng<R, F>(width: u8, depth: u8, rng: &mut R, f: F) -> TileMap<T>
+    where
+        T: PartialEq,
+        R: rand::Rng,
+        F: Fn(Cell, &mut R) -> T,
+    {
+        let mut map = TileMap::new(width, depth);
+        for cell in map.grid().cells() {
+            let tile = f(cell, rng);
+            if tile != map.default {
+                map.insert(cell, tile);
+            }
+        }
+        map
+    }
+
+    /// Overwrites every cell of the inner `Grid` with the tile, sampled from the given `dist` with the given `rng`,
+    /// in the row-major order, storing only the non-default tiles
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use rand::{Rng, SeedableRng, distr::Distribution, rngs::StdRng};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Tree,
+    ///     Water,
+    ///     #[default]
+    ///     Grass,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Grass => "[\"]".green(),
+    ///             Self::Tree => "[T]".dark_green().bold(),
+    ///             Self::Water => "[~]".blue().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// struct Forest;
+    ///
+    /// impl Distribution<Terrain> for Forest {
+    ///     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Terrain {
+    ///         if rng.random_bool(0.3) { Terrain::Tree } else { Terrain::Grass }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(10, 10);
+    /// map.randomize(&mut StdRng::seed_from_u64(42), Forest);
+    /// // some trees are sampled, and the sampled grass is not stored:
+    /// assert!(0 < map.len() && map.len() < 100);
+    /// assert!(map.values().all(|tile| *tile == Terrain::Tree));
+    /// assert!(!map.values().any(|tile| *tile == Terrain::Grass));
+    /// map.randomize_uniform(&mut StdRng::seed_from_u64(42), &[Terrain::Water, Terrain::Grass]);
+    /// assert!(0 < map.len() && map.len() < 100);
+    /// assert!(map.values().all(|tile| *tile == Terrain::Water));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn randomize<R, D>(&mut self, rng: &mut R, dist: D)
+    where
+        T: PartialEq,
+        R: rand::Rng,
+        D: rand::distr::Distribution<T>,
+    {
+        self.gridmap.clear();
+        for cell in self.grid().cells() {
+            let tile = dist.sample(rng);
+            if tile != self.default {
+                self.gridmap.insert(cell, tile);
+            }
+        }
+    }
+
+    /// Overwrites every cell of the inner `Grid` with the tile, picked uniformly from the given `choices`
+    /// with the given `rng`, in the row-major order, storing only the non-default tiles
+    ///
+    /// If `choices` is empty, every cell becomes vacant
+    ///
+    /// For examples, see the `randomize` method
+    ///
+    #[cfg(feature = "rand")]
+    pub fn randomize_uniform<R>(&mut self, rng: &mut R, choices: &[T])
+    where
+        T: PartialEq + Clone,
+        R: rand::Rng,
+    {
+        self.gridmap.clear();
+        if choices.is_empty() {
+            return;
+        }
+        for cell in self.grid().cells() {
+            let tile = &choices[rng.random_range(0..choices.len())];
+            if *tile != self.default {
+                self.gridmap.insert(cell, tile.clone());
+            }
+        }
+    }
+
+    /// Places the given `tile` at every cell of the inner `Grid` with the given `density` probability,
+    /// using the given `rng` in the row-major order, and returns the number of placed tiles
+    ///
+    /// The `density` is clamped to `0.0..=1.0`, and `NaN` is treated as `0.0`.
+    /// If `overwrite` is `false`, only the cells with the default tile are candidates, otherwise the existing tiles can be replaced.
+    /// The default `tile` is never stored, so scattering it only clears the replaced tiles, and counts them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use rand::{SeedableRng, rngs::StdRng};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     #[default]
+    ///     Grass,
+    ///     Rock,
+    ///     Wall,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Grass => "[\"]".green(),
+    ///             Self::Rock => "[o]".grey(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(10, 10);
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// // rocks on every free cell, walls are kept:
+    /// assert_eq!(map.scatter(Terrain::Rock, 2.0, &mut rng, false), 99);
+    /// assert_eq!(map.get(&Cell::new(0, 0)), Some(&Terrain::Wall));
+    /// assert_eq!(map.scatter(Terrain::Wall, 0.0, &mut rng, true), 0);
+    /// // scattering the default tile only clears, and keeps the map sparse:
+    /// assert_eq!(map.scatter(Terrain::Grass, 1.0, &mut rng, false), 0);
+    /// assert_eq!(map.scatter(Terrain::Grass, 1.0, &mut rng, true), 100);
+    /// assert!(map.is_empty());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn scatter<R: rand::Rng>(
+        &mut self,
+        tile: T,
+        density: f32,
+        rng: &mut R,
+        overwrite: bool,
+    ) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        let density = if density.is_nan() {
+            0.0
+        } else {
+            density.clamp(0.0, 1.0) as f64
+        };
+        let mut placed = 0;
+        for cell in self.grid().cells() {
+            if (overwrite || *self.tile_at(cell) == self.default) && rng.random_bool(density) {
+                if tile == self.default {
+                    placed += self.remove(&cell).is_some() as usize;
+                } else {
+                    self.gridmap.insert(cell, tile.clone());
+                    placed += 1;
+                }
+            }
+        }
+        placed
+    }
+
+    /// Overwrites every cell of the inner `Grid` with the tile, produced by the `tile_fn` from the value
+    /// of the given `noise_fn`, storing only the non-default tiles
+    ///
+    /// The `noise_fn` is called with the column and the row of every cell, divided by the width and the depth
+    /// of the map, and is expected to return the noise value in the `0..1` range, so any noise implementation
+    /// can be plugged in without this crate depending on it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     #[default]
+    ///     Grass,
+    ///     Water,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Grass => "[\"]".green(),
+    ///             Self::Water => "[~]".blue().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(4, 4);
+    /// // a simple gradient instead of the real noise, water on the left half:
+    /// map.noise_generate(|x, _| x, |value| if value < 0.5 { Terrain::Water } else { Terrain::Grass });
+    /// assert_eq!(map.len(), 8);
+    /// assert_eq!(map.get(&Cell::new(1, 3)), Some(&Terrain::Water));
+    /// assert_eq!(map.get(&Cell::new(2, 0)), None);
+    /// ```
+    pub fn noise_generate<F: Fn(f32, f32) -> f32, G: Fn(f32) -> T>(
+        &mut self,
+        noise_fn: F,
+        tile_fn: G,
+    ) where
+        T: PartialEq,
+    {
+        self.gridmap.clear();
+        let start = self.grid().start();
+        let (width, depth) = (self.grid().width() as f32, self.grid().depth() as f32);
+        for cell in self.grid().cells() {
+            let col = (cell.global_width() - start.global_width()) as f32;
+            let row = (cell.global_depth() - start.global_depth()) as f32;
+            let tile = tile_fn(noise_fn(col / width, row / depth));
+            if tile != self.default {
+                self.gridmap.insert(cell, tile);
+            }
+        }
+    }
+
+    /// Returns the `Dimensions` of the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Dimensions, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(7, 5);
+    /// assert_eq!(map.dimensions(), Dimensions { width: 7, depth: 5 });
+    /// ```
+    pub fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            width: self.grid().width(),
+            depth: self.grid().depth(),
+        }
+    }
+
+    /// Returns ref to the inner `GridMap<T>`
+    ///
+    /// This is the explicit alternative to the `Deref` implementation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(7, 5);
+    /// map.insert(Cell::new(1, 1), Empty);
+    /// let gridmap = map.gridmap_ref();
+    /// assert_eq!(gridmap.grid(), Grid::new(7, 5));
+    /// assert_eq!(gridmap.len(), 1);
+    /// ```
+    pub fn gridmap_ref(&self) -> &GridMap<T> {
+        &self.gridmap
+    }
+
+    /// Consumes the `TileMap<T>`, and returns the inner `GridMap<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(7, 5);
+    /// map.insert(Cell::new(1, 1), Empty);
+    /// let gridmap: GridMap<Empty> = map.into_gridmap();
+    /// assert!(gridmap.contains_key(&Cell::new(1, 1)));
+    /// ```
+    pub fn into_gridmap(self) -> GridMap<T> {
+        self.gridmap
+    }
+
+    /// Inserts the `tile` at the given `cell`, and returns the previous tile at this cell, if there was one
+    ///
+    /// Unlike the `insert` method of the inner `GridMap<T>`, doesn't panic if the `cell` is out of bounds
+    ///
+    /// # Errors
+    /// Returns `OutOfBoundsError` if the `cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// struct Wall;
+    ///
+    /// impl Tile for Wall {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[#]".white().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Wall> = TileMap::new(5, 5);
+    /// assert_eq!(map.try_insert(Cell::new(1, 1), Wall), Ok(None));
+    /// assert_eq!(map.try_insert(Cell::new(1, 1), Wall), Ok(Some(Wall)));
+    /// assert!(map.try_insert(Cell::new(5, 1), Wall).is_err());
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn try_insert(&mut self, cell: Cell, tile: T) -> Result<Option<T>, OutOfBoundsError> {
+        OutOfBoundsError::check(cell, self.grid())?;
+        Ok(self.gridmap.insert(cell, tile))
+    }
+
+    /// Returns the four corner cells of the inner `Grid`,
+    /// in the top left, top right, bottom left, bottom right order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 3);
+    /// assert_eq!(map.corners(), [
+    ///     Cell::new(0, 0),
+    ///     Cell::new(4, 0),
+    ///     Cell::new(0, 2),
+    ///     Cell::new(4, 2),
+    /// ]);
+    /// ```
+    pub fn corners(&self) -> [Cell; 4] {
+        let grid = self.grid();
+        let Dimensions { width, depth } = self.dimensions();
+        [
+            grid.start(),
+            grid.member(width - 1, 0),
+            grid.member(0, depth - 1),
+            grid.end(),
+        ]
+    }
+
+    /// Returns the cell closest to the geometric center of the inner `Grid`,
+    /// biased toward the top left corner if the width or the depth is even
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// assert_eq!(map.center(), Cell::new(2, 2));
+    /// let map: TileMap<Empty> = TileMap::new(4, 6);
+    /// assert_eq!(map.center(), Cell::new(1, 2));
+    /// ```
+    pub fn center(&self) -> Cell {
+        let Dimensions { width, depth } = self.dimensions();
+        self.grid().member((width - 1) / 2, (depth - 1) / 2)
+    }
+
+    /// Returns refs to every tile in the given `row`, from left to right,
+    /// using `T::default()` for the vacant cells, or `None` if the `row` is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 3);
+    /// map.insert(Cell::new(1, 2), Terrain::Wall);
+    /// let row = map.get_row(2).expect("row should be within the map!");
+    /// assert_eq!(row, vec![&Terrain::Floor, &Terrain::Wall, &Terrain::Floor]);
+    /// assert_eq!(map.get_row(3), None);
+    /// ```
+    pub fn get_row(&self, row: u8) -> Option<Vec<&T>> {
+        let row = self.grid().rows().nth(row as usize)?;
+        Some(row.cells().map(|cell| self.tile_at(cell)).collect())
+    }
+
+    /// Returns refs to every tile in the given `col`, from top to bottom,
+    /// using `T::default()` for the vacant cells, or `None` if the `col` is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 3);
+    /// map.insert(Cell::new(1, 2), Terrain::Wall);
+    /// let column = map.get_column(1).expect("column should be within the map!");
+    /// assert_eq!(column, vec![&Terrain::Floor, &Terrain::Floor, &Terrain::Wall]);
+    /// assert_eq!(map.get_column(3), None);
+    /// ```
+    pub fn get_column(&self, col: u8) -> Option<Vec<&T>> {
+        let column = self.grid().columns().nth(col as usize)?;
+        Some(column.cells().map(|cell| self.tile_at(cell)).collect())
+    }
+
+    /// Sets every tile in the given `row` from the `tiles` slice, from left to right
+    ///
+    /// Tiles equal to `T::default()` are removed from the map instead of being stored
+    ///
+    /// # Errors
+    /// Returns `RowError` if the `row` is out of bounds, or if the length of `tiles` doesn't match the width
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{RowError, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 3);
+    /// map.set_row(1, &[Terrain::Wall, Terrain::Floor, Terrain::Wall]).expect("row should fit!");
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&Cell::new(2, 1)), Some(&Terrain::Wall));
+    /// assert_eq!(map.set_row(3, &[]), Err(RowError::OutOfBounds(3)));
+    /// ```
+    pub fn set_row(&mut self, row: u8, tiles: &[T]) -> Result<(), RowError>
+    where
+        T: Clone + PartialEq,
+    {
+        let cells = self
+            .grid()
+            .rows()
+            .nth(row as usize)
+            .ok_or(RowError::OutOfBounds(row))?;
+        self.set_cells(cells, tiles)
+    }
+
+    /// Sets every tile in the given `col` from the `tiles` slice, from top to bottom
+    ///
+    /// Tiles equal to `T::default()` are removed from the map instead of being stored
+    ///
+    /// # Errors
+    /// Returns `RowError` if the `col` is out of bounds, or if the length of `tiles` doesn't match the depth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{RowError, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 3);
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// map.set_column(0, &[Terrain::Floor, Terrain::Wall, Terrain::Wall]).expect("column should fit!");
+    /// assert_eq!(map.get(&Cell::new(0, 0)), None);
+    /// assert_eq!(map.get(&Cell::new(0, 2)), Some(&Terrain::Wall));
+    /// assert_eq!(
+    ///     map.set_column(0, &[Terrain::Wall]),
+    ///     Err(RowError::LengthMismatch { expected: 3, found: 1 })
+    /// );
+    /// ```
+    pub fn set_column(&mut self, col: u8, tiles: &[T]) -> Result<(), RowError>
+    where
+        T: Clone + PartialEq,
+    {
+        let cells = self
+            .grid()
+            .columns()
+            .nth(col as usize)
+            .ok_or(RowError::OutOfBounds(col))?;
+        self.set_cells(cells, tiles)
+    }
+
+    /// Returns new `TileMap<T>` with swapped rows and columns, so the tile at (width, depth)
+    /// is moved to (depth, width), and the width and the depth of the map are swapped too
+    ///
+    /// Returned map has the same `Formatting`, and the inner `Grid` starting at (0,0)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(5, 3);
+    /// map.insert(Cell::new(4, 1), Empty);
+    /// let transposed = map.transpose();
+    /// assert_eq!(transposed.grid(), Grid::new(3, 5));
+    /// assert_eq!(transposed.get(&Cell::new(1, 4)), Some(&Empty));
+    /// ```
+    pub fn transpose(&self) -> TileMap<T>
+    where
+        T: Clone,
+    {
+        let grid = self.grid();
+        let mut transposed = TileMap::formatted(grid.depth(), grid.width(), self.formatting);
+        for (cell, tile) in self.iter() {
+            transposed.insert(Cell::new(cell.depth(grid), cell.width(grid)), tile.clone());
+        }
+        transposed
+    }
+
+    /// Returns every cell of the inner `Grid` within the `radius` from the `center` under the given `metric`,
+    /// sorted by distance, and then in the row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{DistanceMetric, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let cells = map.cells_within_radius(Cell::new(0, 0), 1, DistanceMetric::Manhattan);
+    /// assert_eq!(cells, vec![Cell::new(0, 0), Cell::new(1, 0), Cell::new(0, 1)]);
+    /// let cells = map.cells_within_radius(Cell::new(2, 2), 1, DistanceMetric::Chebyshev);
+    /// assert_eq!(cells.len(), 9);
+    /// ```
+    pub fn cells_within_radius(
+        &self,
+        center: Cell,
+        radius: u8,
+        metric: DistanceMetric,
+    ) -> Vec<Cell> {
+        let mut cells: Vec<Cell> = self
+            .grid()
+            .cells()
+            .filter(|&cell| metric.distance(center, cell) <= radius as u16)
+            .collect();
+        cells.sort_by_key(|&cell| metric.distance(center, cell));
+        cells
+    }
+
+    /// Returns every cell of the inner `Grid` within the `radius` steps from the `origin`,
+    /// which direction from the `origin` deviates from the given `direction` by at most `half_angle_deg` degrees
+    ///
+    /// Steps are measured by the `DistanceMetric::Chebyshev`, and the `origin` itself is not included.
+    /// Cells are sorted by distance, then by the deviation angle, and then in the row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Direction, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(10, 10);
+    /// let cone = map.cone_area(Cell::new(5, 5), Direction::North, 2, 45);
+    /// assert_eq!(
+    ///     cone,
+    ///     vec![
+    ///         Cell::new(5, 4),
+    ///         Cell::new(4, 4),
+    ///         Cell::new(6, 4),
+    ///         Cell::new(5, 3),
+    ///         Cell::new(4, 3),
+    ///         Cell::new(6, 3),
+    ///         Cell::new(3, 3),
+    ///         Cell::new(7, 3),
+    ///     ]
+    /// );
+    /// ```
+    pub fn cone_area(
+        &self,
+        origin: Cell,
+        direction: Direction,
+        radius: u8,
+        half_angle_deg: u8,
+    ) -> Vec<Cell> {
+        let (dir_width, dir_depth) = direction.offset();
+        let dir_length = ((dir_width * dir_width + dir_depth * dir_depth) as f32).sqrt();
+        let mut cone: Vec<(u16, f32, Cell)> = self
+            .grid()
+            .cells()
+            .filter_map(|cell| {
+                let distance = chebyshev_distance(origin, cell);
+                if distance == 0 || distance > radius as u16 {
+                    return None;
+                }
+                let width = cell.global_width() as i16 - origin.global_width() as i16;
+                let depth = cell.global_depth() as i16 - origin.global_depth() as i16;
+                let length = ((width * width + depth * depth) as f32).sqrt();
+                let cos = (width * dir_width + depth * dir_depth) as f32 / (length * dir_length);
+                let angle = cos.clamp(-1.0, 1.0).acos().to_degrees();
+                // small epsilon keeps the cells exactly on the edge of the cone:
+                (angle <= half_angle_deg as f32 + 1e-3).then_some((distance, angle, cell))
+            })
+            .collect();
+        cone.sort_by(|(a_distance, a_angle, _), (b_distance, b_angle, _)| {
+            a_distance.cmp(b_distance).then(a_angle.total_cmp(b_angle))
+        });
+        cone.into_iter().map(|(_, _, cell)| cell).collect()
+    }
+
+    /// Returns the explicitly inserted tile closest to the `origin` under the given `metric`, with its cell,
+    /// or `None` if the map is empty
+    ///
+    /// Ties are broken by the row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{DistanceMetric, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Enemy,
+    ///     Coin,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Coin => "[$]".yellow().bold(),
+    ///             Self::Enemy => "[@]".red().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(10, 10);
+    /// map.insert(Cell::new(2, 2), Entity::Enemy);
+    /// map.insert(Cell::new(6, 6), Entity::Coin);
+    /// let origin = Cell::new(4, 5);
+    /// assert_eq!(
+    ///     map.closest_to(origin, DistanceMetric::Manhattan),
+    ///     Some((Cell::new(6, 6), &Entity::Coin))
+    /// );
+    /// assert_eq!(
+    ///     map.closest_matching(origin, DistanceMetric::Manhattan, |tile| *tile == Entity::Enemy),
+    ///     Some((Cell::new(2, 2), &Entity::Enemy))
+    /// );
+    /// ```
+    pub fn closest_to(&self, origin: Cell, metric: DistanceMetric) -> Option<(Cell, &T)> {
+        self.closest_matching(origin, metric, |_| true)
+    }
+
+    /// Returns the explicitly inserted tile, that satisfies the `pred` and is closest to the `origin`
+    /// under the given `metric`, with its cell, or `None` if there is no such tile
+    ///
+    /// Ties are broken by the row-major order
+    ///
+    /// For examples, see the `closest_to` method
+    ///
+    pub fn closest_matching<F: Fn(&T) -> bool>(
+        &self,
+        origin: Cell,
+        metric: DistanceMetric,
+        pred: F,
+    ) -> Option<(Cell, &T)> {
+        self.iter()
+            .filter(|(_, tile)| pred(tile))
+            .min_by_key(|(cell, _)| {
+                (
+                    metric.distance(origin, **cell),
+                    cell.global_depth(),
+                    cell.global_width(),
+                )
+            })
+            .map(|(cell, tile)| (*cell, tile))
+    }
+
+    /// Creates new `TileMap<T>` with the empty inner `GridMap<T>` of the largest size,
+    /// that fits into the current terminal window, when drawn with the default `Formatting`
+    ///
+    /// The width of every tile is measured on the `T::default()` tile
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the terminal size can't be queried
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new_fit_terminal().expect("should be able to query terminal size!");
+    /// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// ```
+    pub fn new_fit_terminal() -> io::Result<TileMap<T>> {
+        let mut map = TileMap::new(1, 1);
+        map.resize_to_terminal()?;
+        Ok(map)
+    }
+
+    /// Resizes the inner `Grid` to the largest size, that fits into the current terminal window,
+    /// when drawn with the inner `Formatting` rules.
+    /// Returns `true` if the size has changed, and `false` if not
+    ///
+    /// Tiles that are not within the resized `Grid` are removed
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the terminal size can't be queried
+    ///
+    pub fn resize_to_terminal(&mut self) -> io::Result<bool> {
+        let (columns, rows) = terminal::size()?;
+        let tile_spacing = self.formatting.tile_spacing as u16;
+        let row_spacing = self.formatting.row_spacing as u16;
+        let columns = columns.saturating_sub(self.formatting.left_indent as u16 * TAB_WIDTH);
+        let rows = rows.saturating_sub(
+            self.formatting.top_indent as u16 + self.formatting.bottom_indent as u16,
+        );
+        // the grid can't overflow `u8::MAX` from its start:
+        let start = self.grid().start();
+        let max_width = (u8::MAX - start.global_width()).saturating_add(1) as u16;
+        let max_depth = (u8::MAX - start.global_depth()).saturating_add(1) as u16;
+        let width = columns / (self.tile_width() + tile_spacing).max(1);
+        let depth = rows / (row_spacing + 1);
+        let grid = Grid::indented(
+            width.clamp(1, max_width) as u8,
+            depth.clamp(1, max_depth) as u8,
+            start.into(),
+        );
+        if grid == self.grid() {
+            return Ok(false);
+        }
+        self.set_grid(grid);
+        Ok(true)
+    }
+
+    /// Expands the inner `Grid` to the right by `extra_cols` columns, keeping every existing tile,
+    /// so the new cells are vacant
+    ///
+    /// # Errors
+    /// Returns `OverflowError` if the new width or the new last column would exceed `u8::MAX`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Dimensions, OverflowError, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(4, 4), Empty);
+    /// map.widen(3).expect("should be able to widen the map!");
+    /// map.heighten(1).expect("should be able to heighten the map!");
+    /// assert_eq!(map.dimensions(), Dimensions { width: 8, depth: 6 });
+    /// assert!(map.contains_key(&Cell::new(4, 4)));
+    /// assert_eq!(map.widen(250), Err(OverflowError { size: 8, extra: 250 }));
+    /// ```
+    pub fn widen(&mut self, extra_cols: u8) -> Result<(), OverflowError> {
+        let grid = self.grid();
+        let error = OverflowError {
+            size: grid.width(),
+            extra: extra_cols,
+        };
+        grid.width().checked_add(extra_cols).ok_or(error)?;
+        let end = grid.end();
+        let width = end.global_width().checked_add(extra_cols).ok_or(error)?;
+        self.set_grid(Grid::from((
+            grid.start(),
+            Cell::new(width, end.global_depth()),
+        )));
+        Ok(())
+    }
+
+    /// Expands the inner `Grid` to the bottom by `extra_rows` rows, keeping every existing tile,
+    /// so the new cells are vacant
+    ///
+    /// # Errors
+    /// Returns `OverflowError` if the new depth or the new last row would exceed `u8::MAX`
+    ///
+    /// For examples, see the `widen` method
+    ///
+    pub fn heighten(&mut self, extra_rows: u8) -> Result<(), OverflowError> {
+        let grid = self.grid();
+        let error = OverflowError {
+            size: grid.depth(),
+            extra: extra_rows,
+        };
+        grid.depth().checked_add(extra_rows).ok_or(error)?;
+        let end = grid.end();
+        let depth = end.global_depth().checked_add(extra_rows).ok_or(error)?;
+        self.set_grid(Grid::from((
+            grid.start(),
+            Cell::new(end.global_width(), depth),
+        )));
+        Ok(())
+    }
+
+    /// Shrinks the inner `Grid` to the bounding box of the explicitly inserted tiles,
+    /// and moves the tiles so the bounding box starts at (0,0)
+    ///
+    /// If the map is empty, it becomes 1x1.
+    /// Returns the number of the trimmed rows and columns, as the `(rows, columns)` pair
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Dimensions, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(10, 8);
+    /// map.insert(Cell::new(2, 3), Empty);
+    /// map.insert(Cell::new(5, 4), Empty);
+    /// assert_eq!(map.shrink_to_fit(), (6, 6));
+    /// assert_eq!(map.dimensions(), Dimensions { width: 4, depth: 2 });
+    /// assert!(map.contains_key(&Cell::new(0, 0)));
+    /// assert!(map.contains_key(&Cell::new(3, 1)));
+    /// ```
+    pub fn shrink_to_fit(&mut self) -> (u8, u8) {
+        let before = self.dimensions();
+        let bounds = self
+            .keys()
+            .fold(None, |bounds: Option<(u8, u8, u8, u8)>, cell| {
+                let (width, depth) = (cell.global_width(), cell.global_depth());
+                Some(match bounds {
+                    None => (width, depth, width, depth),
+                    Some((min_w, min_d, max_w, max_d)) => (
+                        min_w.min(width),
+                        min_d.min(depth),
+                        max_w.max(width),
+                        max_d.max(depth),
+                    ),
+                })
+            });
+        self.gridmap = match bounds {
+            None => GridMap::new(1, 1),
+            Some((min_w, min_d, max_w, max_d)) => {
+                let tiles: HashMap<Cell, T> = std::mem::take(&mut *self.gridmap)
+                    .into_iter()
+                    .map(|(cell, tile)| {
+                        let cell =
+                            Cell::new(cell.global_width() - min_w, cell.global_depth() - min_d);
+                        (cell, tile)
+                    })
+                    .collect();
+                GridMap::from((Grid::new(max_w - min_w + 1, max_d - min_d + 1), tiles))
+            }
+        };
+        let after = self.dimensions();
+        (before.depth - after.depth, before.width - after.width)
+    }
+
+    /// Returns an iterator over every row of the `TileMap<T>`, from top to bottom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 3);
+    /// map.set_row(1, &[Terrain::Wall, Terrain::Wall, Terrain::Wall]).expect("row should fit!");
+    /// let walls: Vec<u8> = map
+    ///     .row_iter()
+    ///     .filter(|row| row.cells().all(|(_, tile)| *tile == Terrain::Wall))
+    ///     .map(|row| row.row_index())
+    ///     .collect();
+    /// assert_eq!(walls, vec![1]);
+    /// ```
+    pub fn row_iter(&self) -> impl Iterator<Item = RowRef<'_, T>> {
+        self.grid()
+            .rows()
+            .enumerate()
+            .map(move |(index, cells)| RowRef {
+                map: self,
+                index: index as u8,
+                cells,
+            })
+    }
+
+    /// Returns an iterator over every column of the `TileMap<T>`, from left to right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 3);
+    /// map.insert(Cell::new(2, 0), Terrain::Wall);
+    /// map.insert(Cell::new(2, 2), Terrain::Wall);
+    /// let counts: Vec<(u8, usize)> = map
+    ///     .col_iter()
+    ///     .map(|column| {
+    ///         let walls = column.cells().filter(|(_, tile)| **tile == Terrain::Wall).count();
+    ///         (column.column_index(), walls)
+    ///     })
+    ///     .collect();
+    /// assert_eq!(counts, vec![(0, 0), (1, 0), (2, 2)]);
+    /// ```
+    pub fn col_iter(&self) -> impl Iterator<Item = ColumnRef<'_, T>> {
+        self.grid()
+            .columns()
+            .enumerate()
+            .map(move |(index, cells)| ColumnRef {
+                map: self,
+                index: index as u8,
+                cells,
+            })
+    }
+
+    /// Returns the read-only `TileMapView<'_, T>` of the `TileMap<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap, TileMapView};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn render(view: TileMapView<'_, Entity>) {
+    ///     assert_eq!(view.get(&Cell::new(1, 2)), Some(&Entity::Hero));
+    ///     view.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 2), Entity::Hero);
+    /// render(map.freeze());
+    /// ```
+    pub fn freeze(&self) -> TileMapView<'_, T> {
+        TileMapView { map: self }
+    }
+
+    /// Returns the `TileMapSlice<'_, T>` of the rectangular region of the `TileMap<T>`,
+    /// starting at `top_left` and having the given `width` and `depth`
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` < 1, or if the region is not fully within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Dimensions, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(10, 10);
+    /// map.insert(Cell::new(5, 6), Entity::Hero);
+    /// let slice = map.slice(Cell::new(4, 4), 3, 3);
+    /// assert_eq!(slice.dimensions(), Dimensions { width: 3, depth: 3 });
+    /// assert_eq!(slice.get(Cell::new(1, 2)), &Entity::Hero);
+    /// assert_eq!(slice.iter().filter(|(_, tile)| **tile == Entity::Hero).count(), 1);
+    /// slice.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// ```
+    pub fn slice(&self, top_left: Cell, width: u8, depth: u8) -> TileMapSlice<'_, T> {
+        self.try_slice(top_left, width, depth).unwrap_or_else(|_| {
+            panic!(
+                "slice is not within the tilemap! top left:{top_left}, width:{width}, depth:{depth}"
+            )
+        })
+    }
+
+    /// Returns the `TileMapSlice<'_, T>` in the same way as the `slice` method does, but without panicking
+    ///
+    /// # Errors
+    /// Returns `TileError::Dimension` if `width` or `depth` < 1, or `TileError::OutOfBounds`
+    /// with the top left or the bottom right corner, if the region is not fully within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{DimensionError, OutOfBoundsError, Tile, TileError, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(10, 10);
+    /// assert!(map.try_slice(Cell::new(4, 4), 3, 3).is_ok());
+    /// assert!(matches!(
+    ///     map.try_slice(Cell::new(4, 4), 0, 3),
+    ///     Err(TileError::Dimension(DimensionError::Empty))
+    /// ));
+    /// assert!(matches!(
+    ///     map.try_slice(Cell::new(8, 4), 3, 3),
+    ///     Err(TileError::OutOfBounds(OutOfBoundsError { cell, .. })) if cell == Cell::new(10, 6)
+    /// ));
+    /// ```
+    pub fn try_slice(
+        &self,
+        top_left: Cell,
+        width: u8,
+        depth: u8,
+    ) -> Result<TileMapSlice<'_, T>, TileError> {
+        if width < 1 || depth < 1 {
+            return Err(DimensionError::Empty.into());
+        }
+        OutOfBoundsError::check(top_left, self.grid())?;
+        let (end_width, end_depth) = (
+            top_left.global_width() as u16 + width as u16 - 1,
+            top_left.global_depth() as u16 + depth as u16 - 1,
+        );
+        let bottom_right = Cell::new(
+            end_width.min(u8::MAX as u16) as u8,
+            end_depth.min(u8::MAX as u16) as u8,
+        );
+        OutOfBoundsError::check(bottom_right, self.grid())?;
+        if end_width > u8::MAX as u16 || end_depth > u8::MAX as u16 {
+            return Err(OutOfBoundsError {
+                cell: bottom_right,
+                grid_width: self.grid().width(),
+                grid_depth: self.grid().depth(),
+            }
+            .into());
+        }
+        Ok(TileMapSlice {
+            map: self,
+            top_left,
+            width,
+            depth,
+        })
+    }
+
+    /// Calls `f` for every cell of the inner `Grid` and its tile in the row-major order,
+    /// using `T::default()` for the vacant cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(3, 2);
+    /// let mut cells = Vec::new();
+    /// map.for_each(|cell, _| cells.push(cell));
+    /// assert_eq!(cells.len(), 6);
+    /// assert_eq!(cells[1], Cell::new(1, 0));
+    /// ```
+    pub fn for_each<F: FnMut(Cell, &T)>(&self, mut f: F) {
+        for cell in self.grid().cells() {
+            f(cell, self.tile_at(cell));
+        }
+    }
+
+    /// Calls `f` for every cell of the inner `Grid` and mut ref to its tile in the row-major order,
+    /// using `T::default()` for the vacant cells
+    ///
+    /// Tiles that are equal to `T::default()` after the call are removed from the map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 3);
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// // swap walls and floors:
+    /// map.for_each_mut(|_, tile| {
+    ///     *tile = match tile {
+    ///         Terrain::Wall => Terrain::Floor,
+    ///         Terrain::Floor => Terrain::Wall,
+    ///     }
+    /// });
+    /// assert_eq!(map.len(), 8);
+    /// assert_eq!(map.get(&Cell::new(0, 0)), None);
+    /// ```
+    pub fn for_each_mut<F: FnMut(Cell, &mut T)>(&mut self, mut f: F)
+    where
+        T: PartialEq,
+    {
+        for cell in self.grid().cells() {
+            let mut tile = self.remove(&cell).unwrap_or_default();
+            f(cell, &mut tile);
+            if tile != self.default {
+                self.insert(cell, tile);
+            }
+        }
+    }
+
+    /// Calls `f` for every cell of the given `path` in order, with the cell and its tile,
+    /// using `T::default()` for the vacant cells
+    ///
+    /// Cells that are not within the inner `Grid` are skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Trap,
+    ///     Trail,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Trail => "[.]".yellow(),
+    ///             Self::Trap => "[^]".red().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 0), Terrain::Trap);
+    /// let path = [Cell::new(0, 0), Cell::new(1, 0), Cell::new(2, 0)];
+    /// let mut traps = 0;
+    /// map.walk_path(&path, |_, tile| traps += (*tile == Terrain::Trap) as usize);
+    /// assert_eq!(traps, 1);
+    /// // leave a trail, except on the traps:
+    /// map.walk_path_mut(&path, |_, tile| {
+    ///     if *tile == Terrain::Floor {
+    ///         *tile = Terrain::Trail;
+    ///     }
+    /// });
+    /// assert_eq!(map.get(&Cell::new(0, 0)), Some(&Terrain::Trail));
+    /// assert_eq!(map.get(&Cell::new(1, 0)), Some(&Terrain::Trap));
+    /// ```
+    pub fn walk_path<F: FnMut(Cell, &T)>(&self, path: &[Cell], mut f: F) {
+        for &cell in path.iter().filter(|cell| cell.within(self.grid())) {
+            f(cell, self.tile_at(cell));
+        }
+    }
+
+    /// Calls `f` for every cell of the given `path` in order, with the cell and the mutable ref to its tile,
+    /// using `T::default()` for the vacant cells, and storing only the non-default tiles back
+    ///
+    /// Cells that are not within the inner `Grid` are skipped
+    ///
+    /// For examples, see the `walk_path` method
+    ///
+    pub fn walk_path_mut<F: FnMut(Cell, &mut T)>(&mut self, path: &[Cell], mut f: F)
+    where
+        T: PartialEq,
+    {
+        let grid = self.grid();
+        for &cell in path.iter().filter(|cell| cell.within(grid)) {
+            let mut tile = self.remove(&cell).unwrap_or_default();
+            f(cell, &mut tile);
+            if tile != self.default {
+                self.insert(cell, tile);
+            }
+        }
+    }
+
+    /// Returns an iterator over every cell of the inner `Grid` and its tile, visited in the outward
+    /// clockwise spiral order starting from the `center`, using `T::default()` for the vacant cells
+    ///
+    /// The spiral goes right, down, left, up, and so on, skipping the cells that are not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(3, 3);
+    /// let cells: Vec<Cell> = map.spiral_iter(Cell::new(1, 1)).map(|(cell, _)| cell).collect();
+    /// assert_eq!(cells, vec![
+    ///     Cell::new(1, 1),
+    ///     Cell::new(2, 1),
+    ///     Cell::new(2, 2),
+    ///     Cell::new(1, 2),
+    ///     Cell::new(0, 2),
+    ///     Cell::new(0, 1),
+    ///     Cell::new(0, 0),
+    ///     Cell::new(1, 0),
+    ///     Cell::new(2, 0),
+    /// ]);
+    /// // cells outside of the grid are skipped:
+    /// assert_eq!(map.spiral_iter(Cell::new(0, 0)).count(), 9);
+    /// ```
+    pub fn spiral_iter(&self, center: Cell) -> impl Iterator<Item = (Cell, &T)> {
+        const DIRECTIONS: [(i16, i16); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+        let mut remaining = self.grid().size();
+        let mut position = (center.global_width() as i16, center.global_depth() as i16);
+        let (mut leg, mut step, mut turns) = (1, 0, 0);
+        let mut started = false;
+        std::iter::from_fn(move || {
+            while remaining > 0 {
+                if started {
+                    let (width, depth) = DIRECTIONS[turns % 4];
+                    position = (position.0 + width, position.1 + depth);
+                    step += 1;
+                    if step == leg {
+                        step = 0;
+                        turns += 1;
+                        // every second turn the spiral leg gets longer:
+                        if turns % 2 == 0 {
+                            leg += 1;
+                        }
+                    }
+                }
+                started = true;
+                if let Some(cell) = self.offset(Cell::new(0, 0), position.0, position.1) {
+                    remaining -= 1;
+                    return Some((cell, self.tile_at(cell)));
+                }
+            }
+            None
+        })
+    }
+
+    /// Returns an iterator over the cells at exactly the given Chebyshev `radius` from the `center` and their tiles,
+    /// in the clockwise order starting from the top left corner, using `T::default()` for the vacant cells
+    ///
+    /// Cells that are not within the inner `Grid` are skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let ring: Vec<Cell> = map.ring_iter(Cell::new(1, 1), 1).map(|(cell, _)| cell).collect();
+    /// assert_eq!(ring, vec![
+    ///     Cell::new(0, 0),
+    ///     Cell::new(1, 0),
+    ///     Cell::new(2, 0),
+    ///     Cell::new(2, 1),
+    ///     Cell::new(2, 2),
+    ///     Cell::new(1, 2),
+    ///     Cell::new(0, 2),
+    ///     Cell::new(0, 1),
+    /// ]);
+    /// assert_eq!(map.ring_iter(Cell::new(0, 0), 2).count(), 5);
+    /// assert_eq!(map.ring_iter(Cell::new(2, 2), 0).count(), 1);
+    /// ```
+    pub fn ring_iter(&self, center: Cell, radius: u8) -> impl Iterator<Item = (Cell, &T)> {
+        let radius = radius as i16;
+        let mut offsets: Vec<(i16, i16)> = Vec::new();
+        if radius == 0 {
+            offsets.push((0, 0));
+        }
+        // top edge, right edge, bottom edge, left edge:
+        offsets.extend((-radius..radius).map(|width| (width, -radius)));
+        offsets.extend((-radius..radius).map(|depth| (radius, depth)));
+        offsets.extend((-radius + 1..=radius).rev().map(|width| (width, radius)));
+        offsets.extend((-radius + 1..=radius).rev().map(|depth| (-radius, depth)));
+        offsets.into_iter().filter_map(move |(width, depth)| {
+            let cell = self.offset(center, width, depth)?;
+            Some((cell, self.tile_at(cell)))
+        })
+    }
+
+    /// Returns an iterator over the cells on the four edges of the inner `Grid` and their tiles,
+    /// in the row-major order, using `T::default()` for the vacant cells
+    ///
+    /// If the width or the depth of the map is less than 3, every cell is on the edge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(4, 3);
+    /// assert_eq!(map.border_tiles().count(), 10);
+    /// let map: TileMap<Empty> = TileMap::new(2, 5);
+    /// assert_eq!(map.border_tiles().count(), 10);
+    /// ```
+    pub fn border_tiles(&self) -> impl Iterator<Item = (Cell, &T)> {
+        let grid = self.grid();
+        grid.cells()
+            .filter(move |cell| cell.on_the_edge(grid))
+            .map(|cell| (cell, self.tile_at(cell)))
+    }
+
+    /// Returns an iterator over the cells strictly inside the edges of the inner `Grid` and their tiles,
+    /// in the row-major order, using `T::default()` for the vacant cells
+    ///
+    /// If the width or the depth of the map is less than 3, there are no interior cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(4, 3);
+    /// let interior: Vec<Cell> = map.interior_tiles().map(|(cell, _)| cell).collect();
+    /// assert_eq!(interior, vec![Cell::new(1, 1), Cell::new(2, 1)]);
+    /// let map: TileMap<Empty> = TileMap::new(2, 5);
+    /// assert_eq!(map.interior_tiles().count(), 0);
+    /// ```
+    pub fn interior_tiles(&self) -> impl Iterator<Item = (Cell, &T)> {
+        let grid = self.grid();
+        grid.cells()
+            .filter(move |cell| !cell.on_the_edge(grid))
+            .map(|cell| (cell, self.tile_at(cell)))
+    }
+
+    /// Returns the four diagonal neighbors of the given `cell` and their tiles, in the NE, SE, SW, NW order,
+    /// using `T::default()` for the vacant cells, and `None` for the cells that are not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let [ne, se, sw, nw] = map.diagonal_neighbors(Cell::new(0, 0));
+    /// assert!(ne.is_none() && sw.is_none() && nw.is_none());
+    /// assert_eq!(se.map(|(cell, _)| cell), Some(Cell::new(1, 1)));
+    /// ```
+    pub fn diagonal_neighbors(&self, cell: Cell) -> [Option<(Cell, &T)>; 4] {
+        [(1, -1), (1, 1), (-1, 1), (-1, -1)].map(|(width, depth)| {
+            let neighbor = self.offset(cell, width, depth)?;
+            Some((neighbor, self.tile_at(neighbor)))
+        })
+    }
+
+    /// Returns all eight neighbors of the given `cell` and their tiles, in the N, NE, E, SE, S, SW, W, NW order,
+    /// using `T::default()` for the vacant cells, and `None` for the cells that are not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(2, 1), Terrain::Wall);
+    /// let neighbors = map.neighbors8(Cell::new(2, 2));
+    /// assert_eq!(neighbors[0], Some((Cell::new(2, 1), &Terrain::Wall)));
+    /// assert_eq!(neighbors.iter().flatten().count(), 8);
+    /// assert_eq!(map.neighbors8(Cell::new(4, 4)).iter().flatten().count(), 3);
+    /// ```
+    pub fn neighbors8(&self, cell: Cell) -> [Option<(Cell, &T)>; 8] {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .map(|(width, depth)| {
+            let neighbor = self.offset(cell, width, depth)?;
+            Some((neighbor, self.tile_at(neighbor)))
+        })
+    }
+
+    /// Runs one generation of the cellular automaton over the `TileMap<T>`, replacing every tile
+    /// with the result of the given `rule`, storing only the non-default tiles
+    ///
+    /// The `rule` receives the cell, its current tile, and the tiles of its eight neighbors in the same order
+    /// as the `neighbors8` method returns them, using `T::default()` for the vacant cells,
+    /// and `None` for the cells that are not within the inner `Grid`.
+    /// Results are written to a scratch map first, so every cell sees the tiles of the previous generation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(2, 2), Terrain::Wall);
+    /// // cave smoothing, the cells outside of the map count as walls:
+    /// map.cellular_automaton_step(|_, _, neighbors| {
+    ///     let walls = neighbors
+    ///         .iter()
+    ///         .filter(|neighbor| neighbor.is_none_or(|tile| *tile == Terrain::Wall))
+    ///         .count();
+    ///     if walls >= 5 { Terrain::Wall } else { Terrain::Floor }
+    /// });
+    /// assert_eq!(map.len(), 4);
+    /// assert_eq!(map.get(&Cell::new(2, 2)), None);
+    /// assert_eq!(map.get(&Cell::new(0, 0)), Some(&Terrain::Wall));
+    /// ```
+    pub fn cellular_automaton_step<F: Fn(Cell, &T, [Option<&T>; 8]) -> T>(&mut self, rule: F)
+    where
+        T: PartialEq,
+    {
+        let mut scratch = GridMap::from((self.grid(), HashMap::new()));
+        self.automaton_step_into(&rule, &mut scratch);
+        self.gridmap = scratch;
+    }
+
+    /// Runs the given number of `steps` of the cellular automaton over the `TileMap<T>` in the same way
+    /// as calling the `cellular_automaton_step` method `steps` times does
+    ///
+    /// Only one scratch map is allocated, and it is swapped with the inner `GridMap<T>` after every step
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Life {
+    ///     Alive,
+    ///     #[default]
+    ///     Dead,
+    /// }
+    ///
+    /// impl Tile for Life {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Alive => "[#]".white().bold(),
+    ///             Self::Dead => "[ ]".dark_grey(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Life> = TileMap::new(5, 5);
+    /// // blinker:
+    /// for col in 1..4 {
+    ///     map.insert(Cell::new(col, 2), Life::Alive);
+    /// }
+    /// let life = |_, tile: &Life, neighbors: [Option<&Life>; 8]| {
+    ///     let alive = neighbors.iter().flatten().filter(|tile| ***tile == Life::Alive).count();
+    ///     match (tile, alive) {
+    ///         (Life::Alive, 2 | 3) | (Life::Dead, 3) => Life::Alive,
+    ///         _ => Life::Dead,
+    ///     }
+    /// };
+    /// let mut naive = map.clone();
+    /// naive.cellular_automaton_step(life);
+    /// naive.cellular_automaton_step(life);
+    /// naive.cellular_automaton_step(life);
+    /// map.cellular_automaton_n_steps(life, 3);
+    /// assert_eq!(map.get(&Cell::new(2, 1)), Some(&Life::Alive));
+    /// assert_eq!(map.get(&Cell::new(1, 2)), None);
+    /// assert!(map.diff(&naive).expect("grids should be the same!").is_empty());
+    /// ```
+    pub fn cellular_automaton_n_steps<F: Fn(Cell, &T, [Option<&T>; 8]) -> T>(
+        &mut self,
+        rule: F,
+        steps: u8,
+    ) where
+        T: PartialEq,
+    {
+        let mut scratch = GridMap::from((self.grid(), HashMap::new()));
+        for _ in 0..steps {
+            scratch.clear();
+            self.automaton_step_into(&rule, &mut scratch);
+            std::mem::swap(&mut self.gridmap, &mut scratch);
+        }
+    }
+
+    /// Casts a ray from the `origin` in the given `(width, depth)` direction, and returns the cells it passes through,
+    /// stepping along the Bresenham line for at most `max_steps` steps
+    ///
+    /// The `origin` itself is not included. The ray stops at the first cell, whose tile is `opaque`,
+    /// including that cell, or at the edge of the inner `Grid`. Zero direction returns no cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(10, 10);
+    /// map.insert(Cell::new(4, 2), Terrain::Wall);
+    /// let ray = map.raytrace(Cell::new(0, 0), (2, 1), 10, |tile| *tile == Terrain::Wall);
+    /// assert_eq!(ray, vec![
+    ///     Cell::new(1, 0),
+    ///     Cell::new(2, 1),
+    ///     Cell::new(3, 1),
+    ///     Cell::new(4, 2),
+    /// ]);
+    /// // ray stops at the edge of the grid:
+    /// let ray = map.raytrace(Cell::new(0, 0), (0, 1), 20, |tile| *tile == Terrain::Wall);
+    /// assert_eq!(ray.len(), 9);
+    /// ```
+    pub fn raytrace<F: Fn(&T) -> bool>(
+        &self,
+        origin: Cell,
+        direction: (i8, i8),
+        max_steps: u8,
+        opaque: F,
+    ) -> Vec<Cell> {
+        let (dx, dy) = (direction.0 as i16, direction.1 as i16);
+        let mut ray = Vec::new();
+        if dx == 0 && dy == 0 {
+            return ray;
+        }
+        let (step_x, step_y) = (dx.signum(), dy.signum());
+        let (dx, dy) = (dx.abs(), dy.abs());
+        let (mut x, mut y) = (0, 0);
+        let mut error = dx - dy;
+        for _ in 0..max_steps {
+            let doubled = 2 * error;
+            if doubled > -dy {
+                error -= dy;
+                x += step_x;
+            }
+            if doubled < dx {
+                error += dx;
+                y += step_y;
+            }
+            let Some(cell) = self.offset(origin, x, y) else {
+                break;
+            };
+            ray.push(cell);
+            if opaque(self.tile_at(cell)) {
+                break;
+            }
+        }
+        ray
+    }
+
+    /// Moves the tile from the `from` cell to the `to` cell, replacing the tile at `to` if there is one.
+    /// Returns `true` if the tile was moved, and `false` if not
+    ///
+    /// Nothing happens if there is no explicitly inserted tile at `from`,
+    /// or if `to` is not within the inner `Grid`, so the tile is never lost
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let (from, to) = (Cell::new(1, 1), Cell::new(3, 2));
+    /// map.insert(from, Empty);
+    /// assert!(map.move_tile(from, to));
+    /// assert_eq!(map.get(&from), None);
+    /// assert_eq!(map.get(&to), Some(&Empty));
+    /// // out of bounds target, tile stays in place:
+    /// assert!(!map.move_tile(to, Cell::new(7, 7)));
+    /// assert_eq!(map.get(&to), Some(&Empty));
+    /// // nothing to move:
+    /// assert!(!map.move_tile(from, to));
+    /// ```
+    pub fn move_tile(&mut self, from: Cell, to: Cell) -> bool {
+        if !to.within(self.grid()) {
+            return false;
+        }
+        match self.remove(&from) {
+            Some(tile) => {
+                self.insert(to, tile);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts the clone of the `tile` at every cell of the inner `Grid`, where the `mask` returns `true`,
+    /// leaving other cells unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(4, 4);
+    /// // checkerboard:
+    /// map.apply_mask(|cell| (cell.global_width() + cell.global_depth()) % 2 == 0, Terrain::Wall);
+    /// assert_eq!(map.len(), 8);
+    /// assert_eq!(map.get(&Cell::new(1, 1)), Some(&Terrain::Wall));
+    /// // clear the first row:
+    /// map.apply_mask_clear(|cell| cell.global_depth() == 0);
+    /// assert_eq!(map.len(), 6);
+    /// ```
+    pub fn apply_mask<F: Fn(Cell) -> bool>(&mut self, mask: F, tile: T)
+    where
+        T: Clone,
+    {
+        for cell in self.grid().cells().filter(|&cell| mask(cell)) {
+            self.insert(cell, tile.clone());
+        }
+    }
+
+    /// Removes the tiles at every cell of the inner `Grid`, where the `mask` returns `true`,
+    /// leaving other cells unchanged
+    ///
+    /// For examples, see the `apply_mask` method
+    ///
+    pub fn apply_mask_clear<F: Fn(Cell) -> bool>(&mut self, mask: F) {
+        self.retain(|&cell, _| !mask(cell));
+    }
+
+    /// Calls `f` for every cell within the rectangle from `top_left` to `bottom_right` and its tile,
+    /// replacing the tile with the returned one, and removing it from the map if it equals `T::default()`
+    ///
+    /// The rectangle is clamped to the inner `Grid`, so the out of bounds corners are allowed.
+    /// Uses `T::default()` for the vacant cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Forest,
+    ///     #[default]
+    ///     Grass,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Grass => "[\"]".green(),
+    ///             Self::Forest => "[T]".dark_green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(10, 10);
+    /// // make the north-east quadrant all forest:
+    /// map.apply_to_region(Cell::new(5, 0), Cell::new(20, 4), |_, _| Terrain::Forest);
+    /// assert_eq!(map.len(), 25);
+    /// assert_eq!(map.get(&Cell::new(9, 4)), Some(&Terrain::Forest));
+    /// ```
+    pub fn apply_to_region<F>(&mut self, top_left: Cell, bottom_right: Cell, mut f: F)
+    where
+        T: PartialEq,
+        F: FnMut(Cell, &T) -> T,
+    {
+        let Some(region) = self.clamp_region(top_left, bottom_right) else {
+            return;
+        };
+        for cell in region.cells() {
+            let tile = f(cell, self.tile_at(cell));
+            if tile == self.default {
+                self.remove(&cell);
+            } else {
+                self.insert(cell, tile);
+            }
+        }
+    }
+
+    /// Fills the rectangle between the `from` and the `to` corners with the tiles from the given `sampler`,
+    /// which is called with the position `t` of every cell along the given `axis`, storing only the non-default tiles
+    ///
+    /// `t` goes from `0.0` at the column or the row of the `from` cell to `1.0` at the column or the row of the `to` cell,
+    /// and is `0.0` everywhere if they are the same. The rectangle is clamped to the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Axis, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     #[default]
+    ///     Grass,
+    ///     Sand,
+    ///     Water,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Grass => "[\"]".green(),
+    ///             Self::Sand => "[.]".yellow(),
+    ///             Self::Water => "[~]".blue().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(5, 3);
+    /// // shore from the grass on the left to the water on the right:
+    /// map.gradient_fill(Axis::Horizontal, Cell::new(0, 0), Cell::new(4, 2), |t| match t {
+    ///     t if t < 0.4 => Terrain::Grass,
+    ///     t if t < 0.7 => Terrain::Sand,
+    ///     _ => Terrain::Water,
+    /// });
+    /// assert_eq!(map.get(&Cell::new(1, 1)), None);
+    /// assert_eq!(map.get(&Cell::new(2, 1)), Some(&Terrain::Sand));
+    /// assert_eq!(map.get(&Cell::new(4, 2)), Some(&Terrain::Water));
+    /// ```
+    pub fn gradient_fill<F: Fn(f32) -> T>(&mut self, axis: Axis, from: Cell, to: Cell, sampler: F)
+    where
+        T: PartialEq,
+    {
+        let top_left = Cell::new(
+            from.global_width().min(to.global_width()),
+            from.global_depth().min(to.global_depth()),
+        );
+        let bottom_right = Cell::new(
+            from.global_width().max(to.global_width()),
+            from.global_depth().max(to.global_depth()),
+        );
+        let Some(region) = self.clamp_region(top_left, bottom_right) else {
+            return;
+        };
+        let position = |cell: Cell| match axis {
+            Axis::Horizontal => (cell.global_width(), from.global_width(), to.global_width()),
+            Axis::Vertical => (cell.global_depth(), from.global_depth(), to.global_depth()),
+        };
+        for cell in region.cells() {
+            let (current, start, end) = position(cell);
+            let t = if start == end {
+                0.0
+            } else {
+                (current as f32 - start as f32) / (end as f32 - start as f32)
+            };
+            let tile = sampler(t);
+            if tile == self.default {
+                self.remove(&cell);
+            } else {
+                self.insert(cell, tile);
+            }
+        }
+    }
+
+    /// Returns the number of cells within the rectangle from `top_left` to `bottom_right`, which tiles satisfy the `pred`,
+    /// using `T::default()` for the vacant cells
+    ///
+    /// Out of bounds cells of the rectangle are counted as the cells, containing `T::default()`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// map.insert(Cell::new(1, 1), Terrain::Wall);
+    /// let walls = map.area_count(Cell::new(0, 0), Cell::new(2, 2), |tile| *tile == Terrain::Wall);
+    /// assert_eq!(walls, 2);
+    /// // the rectangle is 6x6, but only 5x5 cells of it are within the map:
+    /// let floors = map.area_count(Cell::new(0, 0), Cell::new(5, 5), |tile| *tile == Terrain::Floor);
+    /// assert_eq!(floors, 34);
+    /// ```
+    pub fn area_count<F: Fn(&T) -> bool>(
+        &self,
+        top_left: Cell,
+        bottom_right: Cell,
+        pred: F,
+    ) -> usize {
+        let span = |from: u8, to: u8| (to as usize + 1).saturating_sub(from as usize);
+        let total = span(top_left.global_width(), bottom_right.global_width())
+            * span(top_left.global_depth(), bottom_right.global_depth());
+        let Some(region) = self.clamp_region(top_left, bottom_right) else {
+            return if pred(&self.default) { total } else { 0 };
+        };
+        let inside = region
+            .cells()
+            .filter(|&cell| pred(self.tile_at(cell)))
+            .count();
+        if pred(&self.default) {
+            inside + total - region.size() as usize
+        } else {
+            inside
+        }
+    }
+
+    /// Returns `true` if there is no explicitly inserted tile within the rectangle from `top_left` to `bottom_right`
+    ///
+    /// Out of bounds cells of the rectangle are considered empty.
+    /// Stops at the first found tile, checking either the cells of the rectangle or the inserted tiles,
+    /// whichever is fewer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(10, 10);
+    /// map.insert(Cell::new(5, 5), Empty);
+    /// assert!(map.empty_region(Cell::new(0, 0), Cell::new(4, 9)));
+    /// assert!(!map.empty_region(Cell::new(3, 3), Cell::new(30, 30)));
+    /// assert!(map.empty_region(Cell::new(20, 20), Cell::new(30, 30)));
+    /// ```
+    pub fn empty_region(&self, top_left: Cell, bottom_right: Cell) -> bool {
+        let Some(region) = self.clamp_region(top_left, bottom_right) else {
+            return true;
+        };
+        if region.size() as usize <= self.len() {
+            !region.cells().any(|cell| self.contains_key(&cell))
+        } else {
+            !self.keys().any(|cell| cell.within(region))
+        }
+    }
+
+    /// Returns `true` if the region of the `TileMap<T>`, starting at `top_left` and having the same `Dimensions`
+    /// as the `other` map, contains the same tiles as the `other` map, using `T::default()` for the vacant cells
+    ///
+    /// Returns `false` if the region is not fully within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut room: TileMap<Terrain> = TileMap::new(2, 2);
+    /// room.insert(Cell::new(1, 1), Terrain::Wall);
+    /// let mut map: TileMap<Terrain> = TileMap::new(10, 10);
+    /// map.insert(Cell::new(5, 4), Terrain::Wall);
+    /// assert!(map.equals_region(Cell::new(4, 3), &room));
+    /// assert!(!map.equals_region(Cell::new(3, 3), &room));
+    /// assert!(!map.equals_region(Cell::new(9, 9), &room));
+    /// ```
+    pub fn equals_region<U>(&self, top_left: Cell, other: &TileMap<U>) -> bool
+    where
+        T: PartialEq<U>,
+        U: Tile + Default,
+    {
+        let (other_grid, other_dimensions) = (other.grid(), other.dimensions());
+        let bottom_right = (
+            top_left.global_width() as u16 + other_dimensions.width as u16 - 1,
+            top_left.global_depth() as u16 + other_dimensions.depth as u16 - 1,
+        );
+        let end = self.grid().end();
+        if !top_left.within(self.grid())
+            || bottom_right.0 > end.global_width() as u16
+            || bottom_right.1 > end.global_depth() as u16
+        {
+            return false;
+        }
+        other_grid.cells().all(|other_cell| {
+            let cell = Cell::new(
+                top_left.global_width() + other_cell.width(other_grid),
+                top_left.global_depth() + other_cell.depth(other_grid),
+            );
+            *self.tile_at(cell) == *other.tile_at(other_cell)
+        })
+    }
+
+    /// Mirrors every explicitly inserted tile across the given `axis` of the inner `Grid`,
+    /// inserting the clone of the tile at the mirrored cell
+    ///
+    /// Already occupied mirrored cells are not overwritten, and if two tiles are mirrored
+    /// to the same vacant cell, the first one in the row-major order is inserted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{SymmetryAxis, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     Water,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///             Self::Water => "[~]".blue().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(5, 4);
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// map.insert(Cell::new(4, 0), Terrain::Water);
+    /// map.symmetry_fill(SymmetryAxis::Vertical);
+    /// // both cells were occupied, so nothing changes:
+    /// assert_eq!(map.len(), 2);
+    /// map.symmetry_fill(SymmetryAxis::Horizontal);
+    /// assert_eq!(map.get(&Cell::new(0, 3)), Some(&Terrain::Wall));
+    /// assert_eq!(map.get(&Cell::new(4, 3)), Some(&Terrain::Water));
+    /// ```
+    pub fn symmetry_fill(&mut self, axis: SymmetryAxis)
+    where
+        T: Clone,
+    {
+        let grid = self.grid();
+        let mirror = |cell: Cell, horizontal: bool, vertical: bool| {
+            let width = cell.width(grid);
+            let depth = cell.depth(grid);
+            grid.member(
+                if vertical {
+                    grid.width() - 1 - width
+                } else {
+                    width
+                },
+                if horizontal {
+                    grid.depth() - 1 - depth
+                } else {
+                    depth
+                },
+            )
+        };
+        let mirrors: &[(bool, bool)] = match axis {
+            SymmetryAxis::Horizontal => &[(true, false)],
+            SymmetryAxis::Vertical => &[(false, true)],
+            SymmetryAxis::Both => &[(true, false), (false, true), (true, true)],
+        };
+        let mut originals: Vec<(Cell, T)> = self
+            .iter()
+            .map(|(cell, tile)| (*cell, tile.clone()))
+            .collect();
+        // if two tiles are mirrored to the same cell, the first one in the row-major order wins:
+        originals.sort_by_key(|(cell, _)| (cell.global_depth(), cell.global_width()));
+        for (cell, tile) in originals {
+            for &(horizontal, vertical) in mirrors {
+                self.vacant_insert(mirror(cell, horizontal, vertical), tile.clone());
+            }
+        }
+    }
+
+    /// Replaces every tile equal to `from` with the clone of `to`, and returns the number of replaced tiles
+    ///
+    /// If `from` is equal to `T::default()`, the vacant cells are replaced too.
+    /// If `to` is equal to `T::default()`, the replaced tiles are removed from the map instead of being stored.
+    /// If `from` is equal to `to`, nothing happens
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     Grass,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///             Self::Grass => "[\"]".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 3);
+    /// map.insert(Cell::new(1, 1), Terrain::Wall);
+    /// assert_eq!(map.replace(Terrain::Floor, Terrain::Grass), 8);
+    /// assert_eq!(map.len(), 9);
+    /// assert_eq!(map.replace(Terrain::Grass, Terrain::Floor), 8);
+    /// assert_eq!(map.len(), 1);
+    /// assert_eq!(map.replace(Terrain::Floor, Terrain::Floor), 0);
+    /// ```
+    pub fn replace(&mut self, from: T, to: T) -> usize
+    where
+        T: Clone + PartialEq,
+    {
+        if from == to {
+            return 0;
+        }
+        let cells: Vec<Cell> = self
+            .grid()
+            .cells()
+            .filter(|&cell| *self.tile_at(cell) == from)
+            .collect();
+        for &cell in &cells {
+            if to == self.default {
+                self.remove(&cell);
+            } else {
+                self.insert(cell, to.clone());
+            }
+        }
+        cells.len()
+    }
+
+    /// Returns every cell, where the tiles of two maps differ, with the tile of the current map
+    /// and the tile of the `other` map, in the row-major order, using `T::default()` for the vacant cells
+    ///
+    /// Only the explicitly inserted tiles of both maps are compared, so the whole `Grid` is not iterated
+    ///
+    /// # Errors
+    /// Returns `DiffError` if the maps have different inner `Grid`s
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut old: TileMap<Terrain> = TileMap::new(5, 5);
+    /// old.insert(Cell::new(1, 1), Terrain::Wall);
+    /// old.insert(Cell::new(2, 2), Terrain::Wall);
+    /// let mut new = old.clone();
+    /// new.remove(&Cell::new(1, 1));
+    /// new.insert(Cell::new(3, 3), Terrain::Wall);
+    /// let diff = old.diff(&new).expect("grids should be the same!");
+    /// assert_eq!(diff, vec![
+    ///     (Cell::new(1, 1), &Terrain::Wall, &Terrain::Floor),
+    ///     (Cell::new(3, 3), &Terrain::Floor, &Terrain::Wall),
+    /// ]);
+    /// assert!(old.diff(&TileMap::new(3, 3)).is_err());
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a TileMap<T>) -> Result<Vec<(Cell, &'a T, &'a T)>, DiffError>
+    where
+        T: PartialEq,
+    {
+        if self.grid() != other.grid() {
+            return Err(DiffError {
+                left: self.grid(),
+                right: other.grid(),
+            });
+        }
+        let cells: HashSet<Cell> = self.keys().chain(other.keys()).copied().collect();
+        let mut diff: Vec<(Cell, &'a T, &'a T)> = cells
+            .into_iter()
+            .map(|cell| (cell, self.tile_at(cell), other.tile_at(cell)))
+            .filter(|(_, tile, other_tile)| tile != other_tile)
+            .collect();
+        diff.sort_by_key(|(cell, _, _)| (cell.global_depth(), cell.global_width()));
+        Ok(diff)
+    }
+
+    /// Inserts every explicitly inserted tile of the `other` map into the current one,
+    /// overwriting the tiles at the same cells, like the `HashMap::extend` does
+    ///
+    /// # Errors
+    /// Returns `MergeError` if the maps have different inner `Grid`s, leaving the current map unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     Door,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///             Self::Door => "[+]".yellow().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut base: TileMap<Terrain> = TileMap::new(5, 5);
+    /// base.insert(Cell::new(1, 0), Terrain::Wall);
+    /// base.insert(Cell::new(2, 0), Terrain::Wall);
+    /// let mut patch: TileMap<Terrain> = TileMap::new(5, 5);
+    /// patch.insert(Cell::new(2, 0), Terrain::Door);
+    /// base.extend_from(&patch).expect("grids should be the same!");
+    /// assert_eq!(base.get(&Cell::new(1, 0)), Some(&Terrain::Wall));
+    /// assert_eq!(base.get(&Cell::new(2, 0)), Some(&Terrain::Door));
+    /// assert!(base.extend_from(&TileMap::new(3, 3)).is_err());
+    /// ```
+    pub fn extend_from(&mut self, other: &TileMap<T>) -> Result<(), MergeError>
+    where
+        T: Clone,
+    {
+        if self.grid() != other.grid() {
+            return Err(MergeError {
+                target: self.grid(),
+                source: other.grid(),
+            });
+        }
+        for (cell, tile) in other.iter() {
+            self.insert(*cell, tile.clone());
+        }
+        Ok(())
+    }
+
+    /// Removes the tiles at every given cell, and returns the number of actually removed tiles
+    ///
+    /// Vacant and out of bounds cells are skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 1), Empty);
+    /// map.insert(Cell::new(2, 2), Empty);
+    /// map.insert(Cell::new(3, 3), Empty);
+    /// let removed = map.batch_remove([Cell::new(1, 1), Cell::new(2, 2), Cell::new(0, 0), Cell::new(9, 9)]);
+    /// assert_eq!(removed, 2);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn batch_remove<I: IntoIterator<Item = Cell>>(&mut self, cells: I) -> usize {
+        cells
+            .into_iter()
+            .filter(|cell| self.remove(cell).is_some())
+            .count()
+    }
+
+    /// Returns the fraction of the cells of the inner `Grid`, that contain an explicitly inserted tile,
+    /// as a value in the `0.0..=1.0` range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     Water,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///             Self::Water => "[~]".blue().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(4, 5);
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// map.insert(Cell::new(1, 0), Terrain::Wall);
+    /// map.insert(Cell::new(2, 0), Terrain::Water);
+    /// map.insert(Cell::new(3, 0), Terrain::Water);
+    /// map.insert(Cell::new(3, 1), Terrain::Water);
+    /// assert_eq!(map.percentage_filled(), 0.25);
+    /// assert_eq!(map.percentage_matching(|tile| *tile == Terrain::Water), 0.15);
+    /// assert_eq!(map.percentage_matching(|tile| *tile == Terrain::Floor), 0.75);
+    /// ```
+    pub fn percentage_filled(&self) -> f32 {
+        self.len() as f32 / self.dimensions().area() as f32
+    }
+
+    /// Returns the fraction of the cells of the inner `Grid`, which tiles satisfy the `pred`,
+    /// as a value in the `0.0..=1.0` range, using `T::default()` for the vacant cells
+    ///
+    /// For examples, see the `percentage_filled` method
+    ///
+    pub fn percentage_matching<F: Fn(&T) -> bool>(&self, pred: F) -> f32 {
+        let matching = self
+            .grid()
+            .cells()
+            .filter(|&cell| pred(self.tile_at(cell)))
+            .count();
+        matching as f32 / self.dimensions().area() as f32
+    }
+
+    /// Removes every explicitly inserted tile from the map, and returns them with their cells in the row-major order
+    ///
+    /// The inner `Grid` and `Formatting` are kept as they are
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Enemy,
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///             Self::Enemy => "[@]".red().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(3, 3), Entity::Enemy);
+    /// map.insert(Cell::new(1, 0), Entity::Hero);
+    /// let drained: Vec<(Cell, Entity)> = map.drain().collect();
+    /// assert_eq!(drained, vec![(Cell::new(1, 0), Entity::Hero), (Cell::new(3, 3), Entity::Enemy)]);
+    /// assert!(map.is_empty());
+    /// assert_eq!(map.dimensions().width, 5);
+    /// ```
+    pub fn drain(&mut self) -> impl Iterator<Item = (Cell, T)> + use<T> {
+        let mut tiles: Vec<(Cell, T)> = self.gridmap.drain().collect();
+        tiles.sort_by_key(|(cell, _)| (cell.global_depth(), cell.global_width()));
+        tiles.into_iter()
+    }
+
+    /// Returns new `TileMap<U>` with the same inner `Grid` and `Formatting`,
+    /// built by calling `f` for every explicitly inserted tile, and storing the non-default results
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Enemy,
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///             Self::Enemy => "[@]".red().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Walkable {
+    ///     No,
+    ///     #[default]
+    ///     Yes,
+    /// }
+    ///
+    /// impl Tile for Walkable {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Yes => "[ ]".white(),
+    ///             Self::No => "[X]".red(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 1), Entity::Enemy);
+    /// map.insert(Cell::new(2, 2), Entity::Hero);
+    /// let walkable: TileMap<Walkable> = map.map(|_, entity| match entity {
+    ///     Entity::Enemy => Walkable::No,
+    ///     _ => Walkable::Yes,
+    /// });
+    /// assert_eq!(walkable.len(), 1);
+    /// assert_eq!(walkable.get(&Cell::new(1, 1)), Some(&Walkable::No));
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> TileMap<U>
+    where
+        U: Tile + Default + PartialEq,
+        F: Fn(Cell, &T) -> U,
+    {
+        let mut mapped = TileMap::from(self.grid());
+        mapped.formatting = self.formatting;
+        for (cell, tile) in self.iter() {
+            let tile = f(*cell, tile);
+            if tile != mapped.default {
+                mapped.insert(*cell, tile);
+            }
+        }
+        mapped
+    }
+
+    /// Returns new `TileMap<V>` with the same inner `Grid` and `Formatting`, built by calling `f`
+    /// for every cell with the tiles of both maps, using `T::default()` and `U::default()` for the vacant cells,
+    /// and storing the non-default results
+    ///
+    /// Cells of the `other` map are matched by their position relative to the start of its inner `Grid`,
+    /// so the maps can start at different cells, and the results are stored at the cells of the current map
+    ///
+    /// # Errors
+    /// Returns `ZipError` if the inner `Grid`s of the maps have different dimensions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".white(),
+    ///             Self::Wall => "[#]".white(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Light {
+    ///     Lit,
+    ///     #[default]
+    ///     Dark,
+    /// }
+    ///
+    /// impl Tile for Light {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Lit => "[*]".yellow(),
+    ///             Self::Dark => "[ ]".black(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum View {
+    ///     Visible(Terrain),
+    ///     #[default]
+    ///     Hidden,
+    /// }
+    ///
+    /// impl Tile for View {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Visible(terrain) => terrain.tile(),
+    ///             Self::Hidden => "[?]".dark_grey(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut terrain: TileMap<Terrain> = TileMap::new(3, 3);
+    /// let mut light: TileMap<Light> = TileMap::new(3, 3);
+    /// terrain.insert(Cell::new(1, 1), Terrain::Wall);
+    /// light.insert(Cell::new(1, 1), Light::Lit);
+    /// light.insert(Cell::new(2, 2), Light::Lit);
+    /// let view = |_, terrain: &Terrain, light: &Light| match light {
+    ///     Light::Lit => View::Visible(*terrain),
+    ///     Light::Dark => View::Hidden,
+    /// };
+    /// let zipped: TileMap<View> = terrain.zip(&light, view).expect("dimensions should be the same!");
+    /// assert_eq!(zipped.len(), 2);
+    /// assert_eq!(zipped.get(&Cell::new(2, 2)), Some(&View::Visible(Terrain::Floor)));
+    /// assert!(terrain.zip(&TileMap::<Light>::new(3, 4), view).is_err());
+    ///
+    /// // the lighting of the 3x3 room, that starts at (4, 2), is matched relative to its start:
+    /// let mut room: TileMap<Light> = TileMap::from(Grid::from((Cell::new(4, 2), Cell::new(6, 4))));
+    /// room.insert(Cell::new(4, 2), Light::Lit);
+    /// let zipped: TileMap<View> = terrain.zip(&room, view).expect("dimensions should be the same!");
+    /// assert_eq!(zipped.grid(), terrain.grid());
+    /// assert_eq!(zipped.len(), 1);
+    /// assert_eq!(zipped.get(&Cell::new(0, 0)), Some(&View::Visible(Terrain::Floor)));
+    /// ```
+    pub fn zip<U, V, F>(&self, other: &TileMap<U>, f: F) -> Result<TileMap<V>, ZipError>
+    where
+        U: Tile + Default,
+        V: Tile + Default + PartialEq,
+        F: Fn(Cell, &T, &U) -> V,
+    {
+        if self.dimensions() != other.dimensions() {
+            return Err(ZipError {
+                left: self.grid(),
+                right: other.grid(),
+            });
+        }
+        let mut zipped = TileMap::from(self.grid());
+        zipped.formatting = self.formatting;
+        for (cell, other_cell) in self.grid().cells().zip(other.grid().cells()) {
+            let tile = f(cell, self.tile_at(cell), other.tile_at(other_cell));
+            if tile != zipped.default {
+                zipped.insert(cell, tile);
+            }
+        }
+        Ok(zipped)
+    }
+
+    /// Returns new `TileMap<U>` with the same inner `Grid` and `Formatting`,
+    /// built by calling `f` for every explicitly inserted tile, and storing only the `Some` results
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Enemy(u8),
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///             Self::Enemy(_) => "[@]".red().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// struct Health(u8);
+    ///
+    /// impl Tile for Health {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[+]".red()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 1), Entity::Enemy(7));
+    /// map.insert(Cell::new(2, 2), Entity::Hero);
+    /// let enemies: TileMap<Health> = map.filter_map(|_, entity| match entity {
+    ///     Entity::Enemy(health) => Some(Health(*health)),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(enemies.len(), 1);
+    /// assert_eq!(enemies.get(&Cell::new(1, 1)), Some(&Health(7)));
+    /// ```
+    pub fn filter_map<U, F>(&self, f: F) -> TileMap<U>
+    where
+        U: Tile + Default,
+        F: Fn(Cell, &T) -> Option<U>,
+    {
+        let mut filtered = TileMap::from(self.grid());
+        filtered.formatting = self.formatting;
+        for (cell, tile) in self.iter() {
+            if let Some(tile) = f(*cell, tile) {
+                filtered.insert(*cell, tile);
+            }
+        }
+        filtered
+    }
+
+    /// Splits the explicitly inserted tiles of the `TileMap<T>` into two new maps with the same `Grid` and `Formatting`,
+    /// the first one with the tiles, that satisfy the `pred`, and the second one with the rest of them
+    ///
+    /// Vacant cells stay vacant in both maps
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Hero,
+    ///     Enemy,
+    ///     Wall,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///             Self::Enemy => "[@]".red().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(0, 0), Entity::Wall);
+    /// map.insert(Cell::new(1, 0), Entity::Wall);
+    /// map.insert(Cell::new(2, 2), Entity::Hero);
+    /// map.insert(Cell::new(3, 3), Entity::Enemy);
+    /// let (terrain, entities) = map.partition(|_, tile| *tile == Entity::Wall);
+    /// assert_eq!(terrain.len(), 2);
+    /// assert_eq!(entities.len(), 2);
+    /// assert_eq!(entities.get(&Cell::new(2, 2)), Some(&Entity::Hero));
+    /// assert_eq!(terrain.grid(), entities.grid());
+    /// ```
+    pub fn partition<F: Fn(Cell, &T) -> bool>(&self, pred: F) -> (TileMap<T>, TileMap<T>)
+    where
+        T: Clone,
+    {
+        let mut matched = TileMap::from(self.grid());
+        matched.formatting = self.formatting;
+        let mut rest = matched.clone();
+        for (cell, tile) in self.iter() {
+            if pred(*cell, tile) {
+                matched.insert(*cell, tile.clone());
+            } else {
+                rest.insert(*cell, tile.clone());
+            }
+        }
+        (matched, rest)
+    }
+
+    /// Returns new `TileMap<T>`, that is just large enough to contain every non-default tile of the current one,
+    /// with the same `Formatting` and the tiles moved so the bounding box starts at (0,0)
+    ///
+    /// If there are no non-default tiles, returns the empty 1x1 `TileMap<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(10, 10);
+    /// map.insert(Cell::new(2, 3), Entity::Hero);
+    /// map.insert(Cell::new(5, 4), Entity::Hero);
+    /// let cropped = map.crop();
+    /// assert_eq!(cropped.grid(), Grid::new(4, 2));
+    /// assert_eq!(cropped.get(&Cell::new(0, 0)), Some(&Entity::Hero));
+    /// assert_eq!(cropped.get(&Cell::new(3, 1)), Some(&Entity::Hero));
+    /// ```
+    pub fn crop(&self) -> TileMap<T>
+    where
+        T: Clone + PartialEq,
+    {
+        let bounds = self
+            .iter()
+            .filter(|(_, tile)| **tile != self.default)
+            .map(|(cell, _)| *cell)
+            .fold(None, |bounds: Option<(Cell, Cell)>, cell| match bounds {
+                None => Some((cell, cell)),
+                Some((start, end)) => Some((
+                    Cell::new(
+                        start.global_width().min(cell.global_width()),
+                        start.global_depth().min(cell.global_depth()),
+                    ),
+                    Cell::new(
+                        end.global_width().max(cell.global_width()),
+                        end.global_depth().max(cell.global_depth()),
+                    ),
+                )),
+            });
+        match bounds {
+            Some((top_left, bottom_right)) => self.crop_to(top_left, bottom_right),
+            None => TileMap::formatted(1, 1, self.formatting),
+        }
+    }
+
+    /// Returns new `TileMap<T>`, containing the tiles within the rectangle from `top_left` to `bottom_right`,
+    /// with the same `Formatting` and the tiles moved so the rectangle starts at (0,0)
+    ///
+    /// # Panics
+    /// Panics if `top_left` or `bottom_right` is not within the inner `Grid`,
+    /// or if `top_left` overflows `bottom_right`. Use the `try_crop_to` method to get the `TileError` instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(10, 10);
+    /// map.insert(Cell::new(3, 3), Empty);
+    /// map.insert(Cell::new(9, 9), Empty);
+    /// let cropped = map.crop_to(Cell::new(2, 2), Cell::new(4, 4));
+    /// assert_eq!(cropped.grid(), Grid::new(3, 3));
+    /// assert_eq!(cropped.len(), 1);
+    /// assert_eq!(cropped.get(&Cell::new(1, 1)), Some(&Empty));
+    /// ```
+    pub fn crop_to(&self, top_left: Cell, bottom_right: Cell) -> TileMap<T>
+    where
+        T: Clone,
+    {
+        top_left.within_panic(self.grid());
+        bottom_right.within_panic(self.grid());
+        let area = Grid::from((top_left, bottom_right));
+        let mut cropped = TileMap::formatted(area.width(), area.depth(), self.formatting);
+        for (cell, tile) in self.iter().filter(|(cell, _)| cell.within(area)) {
+            cropped.insert(Cell::new(cell.width(area), cell.depth(area)), tile.clone());
+        }
+        cropped
+    }
+
+    /// Returns new `TileMap<T>` in the same way as the `crop_to` method does, but without panicking
+    ///
+    /// # Errors
+    /// Returns `TileError::OutOfBounds` if `top_left` or `bottom_right` is not within the inner `Grid`,
+    /// or `TileError::InvertedRegion` if `top_left` overflows `bottom_right`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{OutOfBoundsError, Tile, TileError, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(10, 10);
+    /// let cropped = map.try_crop_to(Cell::new(2, 2), Cell::new(4, 4)).expect("cells should be within the grid!");
+    /// assert_eq!(cropped.grid(), Grid::new(3, 3));
+    /// assert_eq!(
+    ///     map.try_crop_to(Cell::new(2, 2), Cell::new(12, 4)).unwrap_err(),
+    ///     TileError::OutOfBounds(OutOfBoundsError { cell: Cell::new(12, 4), grid_width: 10, grid_depth: 10 })
+    /// );
+    /// assert_eq!(
+    ///     map.try_crop_to(Cell::new(3, 3), Cell::new(1, 1)).unwrap_err(),
+    ///     TileError::InvertedRegion { top_left: Cell::new(3, 3), bottom_right: Cell::new(1, 1) }
+    /// );
+    /// ```
+    pub fn try_crop_to(&self, top_left: Cell, bottom_right: Cell) -> Result<TileMap<T>, TileError>
+    where
+        T: Clone,
+    {
+        OutOfBoundsError::check(top_left, self.grid())?;
+        OutOfBoundsError::check(bottom_right, self.grid())?;
+        if top_left.global_width() > bottom_right.global_width()
+            || top_left.global_depth() > bottom_right.global_depth()
+        {
+            return Err(TileError::InvertedRegion {
+                top_left,
+                bottom_right,
+            });
+        }
+        Ok(self.crop_to(top_left, bottom_right))
+    }
+
+    /// Copies the tiles within the source rectangle from `src_top_left` to `src_bottom_right`
+    /// to the same-sized rectangle, starting at `dst_top_left`, overwriting the tiles there
+    ///
+    /// Vacant source cells make the destination cells vacant too, and the source rectangle is not cleared,
+    /// so the regions may overlap. The source rectangle is clamped to the inner `Grid`,
+    /// and the destination tiles that are not within the inner `Grid` are clipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(10, 5);
+    /// // 2x2 room template with the single wall:
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// map.insert(Cell::new(4, 4), Terrain::Wall);
+    /// map.copy_region_to(Cell::new(0, 0), Cell::new(1, 1), Cell::new(3, 3));
+    /// assert_eq!(map.get(&Cell::new(3, 3)), Some(&Terrain::Wall));
+    /// assert_eq!(map.get(&Cell::new(4, 4)), None);
+    /// // stamping at the edge clips the template:
+    /// map.copy_region_to(Cell::new(0, 0), Cell::new(1, 1), Cell::new(9, 4));
+    /// assert_eq!(map.get(&Cell::new(9, 4)), Some(&Terrain::Wall));
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    pub fn copy_region_to(&mut self, src_top_left: Cell, src_bottom_right: Cell, dst_top_left: Cell)
+    where
+        T: Clone,
+    {
+        let Some(source) = self.clamp_region(src_top_left, src_bottom_right) else {
+            return;
+        };
+        let width = dst_top_left.global_width() as i16 - src_top_left.global_width() as i16;
+        let depth = dst_top_left.global_depth() as i16 - src_top_left.global_depth() as i16;
+        let copied: Vec<(Cell, Option<T>)> = source
+            .cells()
+            .filter_map(|cell| {
+                let target = self.offset(cell, width, depth)?;
+                Some((target, self.get(&cell).cloned()))
+            })
+            .collect();
+        for (cell, tile) in copied {
+            match tile {
+                Some(tile) => self.insert(cell, tile),
+                None => self.remove(&cell),
+            };
+        }
+    }
+
+    /// Moves every explicitly inserted tile, that satisfies the `pred`, to the position of its cell
+    /// after the given `angle` rotation around the `pivot`
+    ///
+    /// All matching tiles are removed first, and then inserted at the new positions, overwriting the tiles there.
+    /// Tiles that are rotated out of the inner `Grid` are discarded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Rotation, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Blade,
+    ///     Hub,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Blade => "[=]".white().bold(),
+    ///             Self::Hub => "[o]".yellow().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(2, 2), Entity::Hub);
+    /// map.insert(Cell::new(3, 2), Entity::Blade);
+    /// map.insert(Cell::new(4, 2), Entity::Blade);
+    /// // spin the blades from the east to the south:
+    /// map.rotate_cells(Cell::new(2, 2), Rotation::Clockwise90, |tile| *tile == Entity::Blade);
+    /// assert_eq!(map.get(&Cell::new(2, 3)), Some(&Entity::Blade));
+    /// assert_eq!(map.get(&Cell::new(2, 4)), Some(&Entity::Blade));
+    /// assert_eq!(map.get(&Cell::new(3, 2)), None);
+    /// // rotating around the corner throws the blades out of the map:
+    /// map.rotate_cells(Cell::new(0, 0), Rotation::Clockwise180, |tile| *tile == Entity::Blade);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn rotate_cells<F: Fn(&T) -> bool>(&mut self, pivot: Cell, angle: Rotation, pred: F) {
+        let cells: Vec<Cell> = self
+            .iter()
+            .filter(|(_, tile)| pred(tile))
+            .map(|(cell, _)| *cell)
+            .collect();
+        let removed: Vec<(Cell, T)> = cells
+            .into_iter()
+            .filter_map(|cell| Some((cell, self.remove(&cell)?)))
+            .collect();
+        for (cell, tile) in removed {
+            let (width, depth) = angle.rotate((
+                cell.global_width() as i16 - pivot.global_width() as i16,
+                cell.global_depth() as i16 - pivot.global_depth() as i16,
+            ));
+            if let Some(target) = self.offset(pivot, width, depth) {
+                self.insert(target, tile);
+            }
+        }
+    }
+
+    /// Splits the `TileMap<T>` into the 2D `Vec` of chunks of `chunk_width` x `chunk_depth` size,
+    /// where every inner `Vec` is the row of chunks, from left to right
+    ///
+    /// Last chunks of every row and column may be smaller, if the size of the map is not divisible by the chunk size.
+    /// Every chunk has the same `Formatting`, and its tiles are moved so the chunk starts at (0,0)
+    ///
+    /// # Errors
+    /// Returns `DimensionError::Empty` if `chunk_width` or `chunk_depth` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{DimensionError, Dimensions, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(10, 7);
+    /// map.insert(Cell::new(5, 4), Empty);
+    /// let chunks = map.chunks(4, 4).expect("chunk size should not be zero!");
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(chunks[0].len(), 3);
+    /// assert_eq!(chunks[1][2].dimensions(), Dimensions { width: 2, depth: 3 });
+    /// assert_eq!(chunks[1][1].get(&Cell::new(1, 0)), Some(&Empty));
+    /// assert_eq!(map.chunks(0, 4).unwrap_err(), DimensionError::Empty);
+    /// ```
+    pub fn chunks(
+        &self,
+        chunk_width: u8,
+        chunk_depth: u8,
+    ) -> Result<Vec<Vec<TileMap<T>>>, DimensionError>
+    where
+        T: Clone,
+    {
+        if chunk_width == 0 || chunk_depth == 0 {
+            return Err(DimensionError::Empty);
+        }
+        let (start, end) = (self.grid().start(), self.grid().end());
+        let starts = |from: u8, to: u8, step: u8| (from..=to).step_by(step as usize);
+        let chunks = starts(start.global_depth(), end.global_depth(), chunk_depth)
+            .map(|depth| {
+                starts(start.global_width(), end.global_width(), chunk_width)
+                    .map(|width| {
+                        let bottom_right = Cell::new(
+                            width
+                                .saturating_add(chunk_width - 1)
+                                .min(end.global_width()),
+                            depth
+                                .saturating_add(chunk_depth - 1)
+                                .min(end.global_depth()),
+                        );
+                        self.crop_to(Cell::new(width, depth), bottom_right)
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(chunks)
+    }
+
+    /// Returns new `TileMap<T>` of the given size, with the same `Formatting`, starting at (0,0),
+    /// where every cell contains the clone of the nearest tile of the scaled `TileMap<T>`
+    ///
+    /// When the map is scaled down, some columns and rows collapse into one,
+    /// and when the map is scaled up, some columns and rows are duplicated
+    ///
+    /// # Panics
+    /// Panics if `new_width` or `new_depth` < 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Dimensions, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(2, 2);
+    /// map.insert(Cell::new(1, 0), Empty);
+    /// let scaled = map.scale(4, 4);
+    /// assert_eq!(scaled.dimensions(), Dimensions { width: 4, depth: 4 });
+    /// assert_eq!(scaled.len(), 4);
+    /// assert!(scaled.contains_key(&Cell::new(2, 0)));
+    /// assert!(scaled.contains_key(&Cell::new(3, 1)));
+    /// let scaled = scaled.scale(2, 2);
+    /// assert_eq!(scaled.iter().collect::<Vec<_>>(), map.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn scale(&self, new_width: u8, new_depth: u8) -> TileMap<T>
+    where
+        T: Clone,
+    {
+        let mut scaled = TileMap::formatted(new_width, new_depth, self.formatting);
+        let (start, dimensions) = (self.grid().start(), self.dimensions());
+        // samples the center of every scaled cell:
+        let nearest = |index: u8, size: u8, new_size: u8| {
+            ((2 * index as u16 + 1) * size as u16 / (2 * new_size as u16)) as u8
+        };
+        for cell in scaled.grid().cells() {
+            let source = Cell::new(
+                start.global_width() + nearest(cell.global_width(), dimensions.width, new_width),
+                start.global_depth() + nearest(cell.global_depth(), dimensions.depth, new_depth),
+            );
+            if let Some(tile) = self.get(&source) {
+                scaled.insert(cell, tile.clone());
+            }
+        }
+        scaled
+    }
+
+    /// Returns the minimap of the `TileMap<T>`, scaled down by the `scale` method to the largest size,
+    /// that fits within the `max_width` x `max_depth` and keeps the aspect ratio of the map
+    ///
+    /// The map is never scaled up, and the minimap is always at least 1x1.
+    /// `Formatting` of the minimap is zeroed, so it is drawn as densely as possible
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Dimensions, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(40, 20);
+    /// let minimap = map.minimap(10, 10);
+    /// assert_eq!(minimap.dimensions(), Dimensions { width: 10, depth: 5 });
+    /// assert_eq!(minimap.formatting.tile_spacing, 0);
+    /// assert_eq!(minimap.formatting.left_indent, 0);
+    /// ```
+    pub fn minimap(&self, max_width: u8, max_depth: u8) -> TileMap<T>
+    where
+        T: Clone,
+    {
+        let Dimensions { width, depth } = self.dimensions();
+        let (width, depth) = (width as u16, depth as u16);
+        let mut new_width = width.min(max_width as u16);
+        let mut new_depth = new_width * depth / width;
+        if new_depth > max_depth as u16 {
+            new_depth = depth.min(max_depth as u16);
+            new_width = new_depth * width / depth;
+        }
+        let mut minimap = self.scale(new_width.max(1) as u8, new_depth.max(1) as u8);
+        minimap.formatting = Formatting {
+            row_spacing: 0,
+            tile_spacing: 0,
+            top_indent: 0,
+            left_indent: 0,
+            right_indent: 0,
+            bottom_indent: 0,
+            tile_separator: None,
+            border_tiles: false,
+            reset_after_each_tile: false,
+        };
+        minimap
+    }
+
+    /// Returns every 4-connected group of cells, whose tiles satisfy the `same_group` predicate,
+    /// using `T::default()` for the vacant cells
+    ///
+    /// Every group is sorted in the row-major order, and groups are sorted by size, biggest first.
+    /// Groups of the same size are kept in the row-major order of their first cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// map.insert(Cell::new(3, 3), Terrain::Wall);
+    /// map.insert(Cell::new(4, 3), Terrain::Wall);
+    /// let walls = map.connected_components(|tile| *tile == Terrain::Wall);
+    /// assert_eq!(walls, vec![
+    ///     vec![Cell::new(3, 3), Cell::new(4, 3)],
+    ///     vec![Cell::new(0, 0)],
+    /// ]);
+    /// ```
+    pub fn connected_components<F: Fn(&T) -> bool>(&self, same_group: F) -> Vec<Vec<Cell>> {
+        let mut visited: HashSet<Cell> = HashSet::new();
+        let mut components: Vec<Vec<Cell>> = Vec::new();
+        for start in self.grid().cells() {
+            if visited.contains(&start) || !same_group(self.tile_at(start)) {
+                continue;
+            }
+            visited.insert(start);
+            let mut component = Vec::new();
+            let mut queue = VecDeque::from([start]);
+            while let Some(cell) = queue.pop_front() {
+                component.push(cell);
+                for (width, depth) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                    if let Some(next) = self.offset(cell, width, depth)
+                        && !visited.contains(&next)
+                        && same_group(self.tile_at(next))
+                    {
+                        visited.insert(next);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            component.sort_by_key(|cell| (cell.global_depth(), cell.global_width()));
+            components.push(component);
+        }
+        components.sort_by_key(|component| std::cmp::Reverse(component.len()));
+        components
+    }
+
+    /// Replaces every tile, 4-connected to the `start` without crossing the `boundary` tiles, with the clone of the `fill`,
+    /// removing the filled tiles from the map if `fill` equals `T::default()`, and returns the number of filled cells
+    ///
+    /// The `boundary` tiles are not changed, and nothing is filled if the `start` is out of bounds,
+    /// or contains the `boundary` tile. Uses `T::default()` for the vacant cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     Carpet,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///             Self::Carpet => "[~]".red(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(5, 3);
+    /// // vertical wall splits the map into two rooms:
+    /// for depth in 0..3 {
+    ///     map.insert(Cell::new(2, depth), Terrain::Wall);
+    /// }
+    /// let filled = map.flood_fill_boundary(Cell::new(0, 0), Terrain::Carpet, Terrain::Wall);
+    /// assert_eq!(filled, 6);
+    /// assert_eq!(map.get(&Cell::new(1, 2)), Some(&Terrain::Carpet));
+    /// assert_eq!(map.get(&Cell::new(3, 0)), None);
+    /// ```
+    pub fn flood_fill_boundary(&mut self, start: Cell, fill: T, boundary: T) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        if !start.within(self.grid()) || *self.tile_at(start) == boundary {
+            return 0;
+        }
+        let mut visited: HashSet<Cell> = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(cell) = queue.pop_front() {
+            for (width, depth) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                if let Some(next) = self.offset(cell, width, depth)
+                    && !visited.contains(&next)
+                    && *self.tile_at(next) != boundary
+                {
+                    visited.insert(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        for &cell in &visited {
+            if fill == self.default {
+                self.remove(&cell);
+            } else {
+                self.insert(cell, fill.clone());
+            }
+        }
+        visited.len()
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout`, using the inner `Formatting` rules
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// ```
+    pub fn draw<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        self.draw_styled(stdout, self.formatting, |_, tile| tile.tile())
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but using the given `formatting` instead of the inner `Formatting` rules, without modifying the map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Formatting, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let minimap = Formatting {
+    ///     row_spacing: 0,
+    ///     tile_spacing: 0,
+    ///     ..Formatting::default()
+    /// };
+    /// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// map.draw_with_formatting_override(&mut stdout(), minimap)
+    ///     .expect("should be able to draw to the stdout!");
+    /// assert_eq!(map.formatting, Formatting::default());
+    ///
+    /// // only the override draws the tiles of the row next to each other:
+    /// let (mut drawn, mut overridden) = (Vec::new(), Vec::new());
+    /// map.draw(&mut drawn).expect("should be able to draw to the buffer!");
+    /// map.draw_with_formatting_override(&mut overridden, minimap)
+    ///     .expect("should be able to draw to the buffer!");
+    /// let row = "[-]".dark_grey().bold().to_string().repeat(5);
+    /// assert!(!String::from_utf8(drawn).expect("should be valid utf-8!").contains(&row));
+    /// assert_eq!(String::from_utf8(overridden).expect("should be valid utf-8!").matches(&row).count(), 5);
+    /// ```
+    pub fn draw_with_formatting_override<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        formatting: Formatting,
+    ) -> io::Result<()> {
+        self.draw_styled(stdout, formatting, |_, tile| tile.tile())
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but resets the terminal colors after every drawn tile, as if the `reset_after_each_tile` was set
+    ///
+    /// Some terminals keep the colors and attributes of the previous tile, so they bleed into the tile spacing,
+    /// resetting them after every tile prevents this
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().on_dark_blue()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.draw_with_ansi_reset(&mut stdout())
+    ///     .expect("should be able to draw to the stdout!");
+    /// assert!(!map.formatting.reset_after_each_tile);
+    ///
+    /// // every tile is followed by the reset:
+    /// let reset_tile = "[-]".dark_grey().on_dark_blue().to_string() + "\x1b[0m";
+    /// let (mut drawn, mut reset) = (Vec::new(), Vec::new());
+    /// map.draw(&mut drawn).expect("should be able to draw to the buffer!");
+    /// map.draw_with_ansi_reset(&mut reset).expect("should be able to draw to the buffer!");
+    /// assert_eq!(String::from_utf8(drawn).expect("should be valid utf-8!").matches(&reset_tile).count(), 0);
+    /// assert_eq!(String::from_utf8(reset).expect("should be valid utf-8!").matches(&reset_tile).count(), 5 * 5);
+    /// ```
+    pub fn draw_with_ansi_reset<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        let formatting = Formatting {
+            reset_after_each_tile: true,
+            ..self.formatting
+        };
+        self.draw_styled(stdout, formatting, |_, tile| tile.tile())
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but the `path_style` is applied on top of the own style of the tiles on the given `path`
+    ///
+    /// The colors, set in the `path_style`, replace the colors of the tiles, and its attributes are added to theirs,
+    /// so the background-only style highlights the path, keeping the foreground and the attributes of the tiles
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let path = [Cell::new(0, 0), Cell::new(1, 0), Cell::new(1, 1)];
+    /// let path_style = ContentStyle::new().on_yellow();
+    /// map.draw_with_path(&mut stdout(), &path, path_style)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// // the path tiles keep their own color and boldness under the yellow background:
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_with_path(&mut buffer, &path, path_style)
+    ///     .expect("should be able to draw to the buffer!");
+    /// let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    /// let on_path = "[-]".dark_grey().bold().on_yellow().to_string();
+    /// assert_eq!(drawn.matches(&on_path).count(), 3);
+    /// assert!(!drawn.contains(&"[-]".on_yellow().to_string()));
+    /// ```
+    pub fn draw_with_path<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        path: &[Cell],
+        path_style: ContentStyle,
+    ) -> io::Result<()> {
+        let path: HashSet<Cell> = path.iter().copied().collect();
+        self.draw_styled(stdout, self.formatting, |cell, tile| {
+            if path.contains(&cell) {
+                overlay(path_style, tile.tile())
+            } else {
+                tile.tile()
+            }
+        })
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` with the given `path` revealed up to the given `step`
+    ///
+    /// The `explored_style` is applied on top of the tiles at `path[0..step]`, and the `path_style` on top of the tile
+    /// at `path[step]`, if it exists, so incrementing the `step` every frame animates the path step by step.
+    /// The `path_style` wins if the path comes back to an explored cell, and the whole path is explored,
+    /// once the `step` reaches the length of the `path`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let path = [Cell::new(0, 0), Cell::new(1, 0), Cell::new(1, 1), Cell::new(0, 0)];
+    /// let path_style = ContentStyle::new().on_yellow();
+    /// let explored_style = ContentStyle::new().on_dark_yellow();
+    /// for step in 0..=path.len() {
+    ///     map.draw_with_path_animated(&mut stdout(), &path, step, path_style, explored_style)
+    ///         .expect("should be able to draw to the stdout!");
+    /// }
+    ///
+    /// // returns the number of the current and the explored tiles, drawn at the given step:
+    /// let count = |step| {
+    ///     let mut buffer: Vec<u8> = Vec::new();
+    ///     map.draw_with_path_animated(&mut buffer, &path, step, path_style, explored_style)
+    ///         .expect("should be able to draw to the buffer!");
+    ///     let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    ///     let current = "[-]".dark_grey().bold().on_yellow().to_string();
+    ///     let explored = "[-]".dark_grey().bold().on_dark_yellow().to_string();
+    ///     (drawn.matches(&current).count(), drawn.matches(&explored).count())
+    /// };
+    /// assert_eq!(count(0), (1, 0));
+    /// assert_eq!(count(2), (1, 2));
+    /// // the current step wins over the explored cell, when the path comes back to it:
+    /// assert_eq!(count(3), (1, 2));
+    /// assert_eq!(count(path.len()), (0, 3));
+    /// ```
+    pub fn draw_with_path_animated<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        path: &[Cell],
+        step: usize,
+        path_style: ContentStyle,
+        explored_style: ContentStyle,
+    ) -> io::Result<()> {
+        let explored: HashSet<Cell> = path[..step.min(path.len())].iter().copied().collect();
+        let current = path.get(step);
+        self.draw_styled(stdout, self.formatting, |cell, tile| {
+            if current == Some(&cell) {
+                overlay(path_style, tile.tile())
+            } else if explored.contains(&cell) {
+                overlay(explored_style, tile.tile())
+            } else {
+                tile.tile()
+            }
+        })
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but the `highlight` style is applied on top of the own style of the tiles at the given `cells`
+    ///
+    /// The colors, set in the `highlight`, replace the colors of the tiles, and the rest of their style is kept,
+    /// so the yellow foreground highlight keeps the tiles bold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::collections::HashSet;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let cells: HashSet<Cell> = HashSet::from([Cell::new(1, 1), Cell::new(3, 2)]);
+    /// map.draw_with_highlighted_cells(&mut stdout(), &cells, ContentStyle::new().yellow())
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_with_highlighted_cells(&mut buffer, &cells, ContentStyle::new().yellow())
+    ///     .expect("should be able to draw to the buffer!");
+    /// let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    /// assert_eq!(drawn.matches(&"[-]".yellow().bold().to_string()).count(), 2);
+    /// assert_eq!(drawn.matches(&"[-]".dark_grey().bold().to_string()).count(), 23);
+    /// ```
+    pub fn draw_with_highlighted_cells<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        cells: &HashSet<Cell>,
+        highlight: ContentStyle,
+    ) -> io::Result<()> {
+        self.draw_styled(stdout, self.formatting, |cell, tile| {
+            if cells.contains(&cell) {
+                overlay(highlight, tile.tile())
+            } else {
+                tile.tile()
+            }
+        })
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but the `cursor_style` is applied on top of the own style of the tile at the given `cursor`
+    ///
+    /// If the `cursor` is out of bounds, it is silently ignored, and the map is drawn as usual
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let cursor_style = ContentStyle::new().black().on_white();
+    /// map.draw_with_cursor(&mut stdout(), Cell::new(2, 3), cursor_style)
+    ///     .expect("should be able to draw to the stdout!");
+    /// map.draw_with_cursor(&mut stdout(), Cell::new(9, 9), cursor_style)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// let (mut drawn, mut with_cursor, mut out_of_bounds) = (Vec::new(), Vec::new(), Vec::new());
+    /// map.draw(&mut drawn).expect("should be able to draw to the buffer!");
+    /// map.draw_with_cursor(&mut with_cursor, Cell::new(2, 3), cursor_style)
+    ///     .expect("should be able to draw to the buffer!");
+    /// map.draw_with_cursor(&mut out_of_bounds, Cell::new(9, 9), cursor_style)
+    ///     .expect("should be able to draw to the buffer!");
+    /// assert_eq!(out_of_bounds, drawn);
+    /// let with_cursor = String::from_utf8(with_cursor).expect("should be valid utf-8!");
+    /// assert_eq!(with_cursor.matches(&"[-]".black().on_white().bold().to_string()).count(), 1);
+    /// ```
+    pub fn draw_with_cursor<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        cursor: Cell,
+        cursor_style: ContentStyle,
+    ) -> io::Result<()> {
+        self.draw_styled(stdout, self.formatting, |cell, tile| {
+            if cell == cursor {
+                overlay(cursor_style, tile.tile())
+            } else {
+                tile.tile()
+            }
+        })
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` with a blinking cursor, using the given `tick` counter
+    ///
+    /// The map is drawn as usual during the first half of every `blink_period_ms`, and in the same way
+    /// as the `draw_with_cursor` method does during the second half, so calling this method every frame
+    /// with a monotonic millisecond counter from the game loop makes the cursor blink without any internal timers.
+    /// If the `blink_period_ms` is 0, the cursor doesn't blink, and is always drawn
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let cursor_style = ContentStyle::new().black().on_white();
+    /// for tick in [0, 250, 500, 750] {
+    ///     map.draw_with_cursor_animated(&mut stdout(), Cell::new(2, 3), cursor_style, tick, 1000)
+    ///         .expect("should be able to draw to the stdout!");
+    /// }
+    /// // steady cursor:
+    /// map.draw_with_cursor_animated(&mut stdout(), Cell::new(2, 3), cursor_style, 42, 0)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// let frame = |tick, blink_period_ms| {
+    ///     let mut buffer: Vec<u8> = Vec::new();
+    ///     map.draw_with_cursor_animated(&mut buffer, Cell::new(2, 3), cursor_style, tick, blink_period_ms)
+    ///         .expect("should be able to draw to the buffer!");
+    ///     buffer
+    /// };
+    /// let (mut hidden, mut shown) = (Vec::new(), Vec::new());
+    /// map.draw(&mut hidden).expect("should be able to draw to the buffer!");
+    /// map.draw_with_cursor(&mut shown, Cell::new(2, 3), cursor_style)
+    ///     .expect("should be able to draw to the buffer!");
+    /// assert_eq!(frame(250, 1000), hidden);
+    /// assert_eq!(frame(750, 1000), shown);
+    /// assert_eq!(frame(250, 0), shown);
+    /// assert_eq!(frame(750, 0), shown);
+    /// ```
+    pub fn draw_with_cursor_animated<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        cursor: Cell,
+        cursor_style: ContentStyle,
+        tick: u64,
+        blink_period_ms: u64,
+    ) -> io::Result<()> {
+        if blink_period_ms > 0 && tick % blink_period_ms < blink_period_ms / 2 {
+            self.draw(stdout)
+        } else {
+            self.draw_with_cursor(stdout, cursor, cursor_style)
+        }
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but the tiles at the cells of the given `markers` are replaced with the paired styled content,
+    /// without modifying the tiles of the map
+    ///
+    /// If the same cell is marked multiple times, the last marker is drawn
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let markers = [
+    ///     (Cell::new(0, 0), "[S]".green()),
+    ///     (Cell::new(4, 4), "[X]".red()),
+    /// ];
+    /// map.draw_with_markers(&mut stdout(), &markers)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// // the last marker of the same cell wins:
+    /// let markers = [
+    ///     (Cell::new(0, 0), "[S]".green()),
+    ///     (Cell::new(4, 4), "[X]".red()),
+    ///     (Cell::new(4, 4), "[!]".yellow()),
+    /// ];
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_with_markers(&mut buffer, &markers)
+    ///     .expect("should be able to draw to the buffer!");
+    /// let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    /// assert_eq!(drawn.matches(&"[S]".green().to_string()).count(), 1);
+    /// assert_eq!(drawn.matches(&"[!]".yellow().to_string()).count(), 1);
+    /// assert!(!drawn.contains("[X]"));
+    /// assert_eq!(drawn.matches("[-]").count(), 23);
+    /// ```
+    pub fn draw_with_markers<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        markers: &[(Cell, StyledContent<&'static str>)],
+    ) -> io::Result<()> {
+        let markers: HashMap<Cell, StyledContent<&'static str>> = markers.iter().copied().collect();
+        self.draw_styled(stdout, self.formatting, |cell, tile| {
+            markers.get(&cell).copied().unwrap_or_else(|| tile.tile())
+        })
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but the tiles within the selection rectangle from `top_left` to `bottom_right` are drawn
+    /// with the `selection_style`, and the tiles on the edge of the rectangle with the `border_style`
+    ///
+    /// The corners can be given in any order, so dragging the selection in any direction works.
+    /// The rectangle is clipped to the map, so only the visible part of the selection is drawn,
+    /// and the map is drawn as usual if the rectangle doesn't overlap it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(7, 7);
+    /// let (selection_style, border_style) = (ContentStyle::new().on_dark_blue(), ContentStyle::new().white().on_blue());
+    /// map.draw_with_selection(&mut stdout(), Cell::new(1, 1), Cell::new(4, 3), selection_style, border_style)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// // returns the number of the selected and the border tiles, drawn with the given corners:
+    /// let count = |first, second| {
+    ///     let mut buffer: Vec<u8> = Vec::new();
+    ///     map.draw_with_selection(&mut buffer, first, second, selection_style, border_style)
+    ///         .expect("should be able to draw to the buffer!");
+    ///     let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    ///     let selected = selection_style.apply("[-]").to_string();
+    ///     let border = border_style.apply("[-]").to_string();
+    ///     (drawn.matches(&selected).count(), drawn.matches(&border).count())
+    /// };
+    /// assert_eq!(count(Cell::new(1, 1), Cell::new(4, 3)), (2, 10));
+    /// // dragged from the bottom right corner:
+    /// assert_eq!(count(Cell::new(4, 3), Cell::new(1, 1)), (2, 10));
+    /// // clipped by the map, so only the top and the left edges are drawn:
+    /// assert_eq!(count(Cell::new(5, 5), Cell::new(9, 9)), (1, 3));
+    /// assert_eq!(count(Cell::new(8, 8), Cell::new(9, 9)), (0, 0));
+    /// ```
+    pub fn draw_with_selection<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        top_left: Cell,
+        bottom_right: Cell,
+        selection_style: ContentStyle,
+        border_style: ContentStyle,
+    ) -> io::Result<()> {
+        let selection = Grid::from((
+            Cell::new(
+                top_left.global_width().min(bottom_right.global_width()),
+                top_left.global_depth().min(bottom_right.global_depth()),
+            ),
+            Cell::new(
+                top_left.global_width().max(bottom_right.global_width()),
+                top_left.global_depth().max(bottom_right.global_depth()),
+            ),
+        ));
+        self.draw_styled(stdout, self.formatting, |cell, tile| {
+            if !cell.within(selection) {
+                tile.tile()
+            } else if cell.on_the_edge(selection) {
+                StyledContent::new(border_style, *tile.tile().content())
+            } else {
+                StyledContent::new(selection_style, *tile.tile().content())
+            }
+        })
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but inside the box, drawn with the characters of the given `border` and the given `border_style`
+    ///
+    /// The box is drawn after the `left_indent`, and the spaces of the `tile_spacing` are added
+    /// before the right side of the box, so the tiles are centered within it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{BorderStyle, Formatting, Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Plain;
+    ///
+    /// impl Tile for Plain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".stylize()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(7, 5);
+    /// map.draw_with_border(&mut stdout(), BorderStyle::Rounded, ContentStyle::new().yellow())
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// // the spacing rows are drawn only between the rows of the map:
+    /// let formatting = Formatting {
+    ///     top_indent: 0,
+    ///     left_indent: 0,
+    ///     bottom_indent: 0,
+    ///     ..Formatting::default()
+    /// };
+    /// let map: TileMap<Plain> = TileMap::formatted(2, 2, formatting);
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_with_border(&mut buffer, BorderStyle::Ascii, ContentStyle::new())
+    ///     .expect("should be able to draw to the buffer!");
+    /// assert_eq!(
+    ///     String::from_utf8(buffer).expect("should be valid utf-8!"),
+    ///     "+---------+\n\r| [-] [-] |\n\r|         |\n\r| [-] [-] |\n\r+---------+\n\r"
+    /// );
+    /// ```
+    pub fn draw_with_border<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        border: BorderStyle,
+        border_style: ContentStyle,
+    ) -> io::Result<()> {
+        let [
+            top_left,
+            horizontal,
+            top_right,
+            vertical,
+            bottom_left,
+            bottom_right,
+        ] = border.chars();
+        let indent = "\t".repeat(self.formatting.left_indent as usize);
+        let spacing = " ".repeat(self.formatting.tile_spacing as usize);
+        let padding = " ".repeat(self.formatting.right_indent as usize);
+        let inner_width = self.row_width() as usize + spacing.len() + padding.len();
+        let line = horizontal.to_string().repeat(inner_width);
+        let side = StyledContent::new(border_style, vertical);
+        execute!(
+            stdout,
+            Print("\n\r".repeat(self.formatting.top_indent as usize)),
+            Print(&indent),
+            PrintStyledContent(StyledContent::new(
+                border_style,
+                format!("{top_left}{line}{top_right}")
+            )),
+            Print("\n\r")
+        )?;
+        for (index, row) in self.grid().rows().enumerate() {
+            // spacing rows are drawn before every row except the first one, so the box fits the map:
+            if index > 0 {
+                for _ in 0..self.formatting.row_spacing {
+                    execute!(
+                        stdout,
+                        Print(&indent),
+                        PrintStyledContent(side),
+                        Print(" ".repeat(inner_width)),
+                        PrintStyledContent(side),
+                        Print("\n\r")
+                    )?;
+                }
+            }
+            execute!(stdout, Print(&indent), PrintStyledContent(side))?;
+            self.queue_row(stdout, self.formatting, row.cells(), &|_, tile: &T| {
+                tile.tile()
+            })?;
+            execute!(
+                stdout,
+                Print(&spacing),
+                Print(&padding),
+                PrintStyledContent(side),
+                Print("\n\r")
+            )?;
+        }
+        execute!(
+            stdout,
+            Print(&indent),
+            PrintStyledContent(StyledContent::new(
+                border_style,
+                format!("{bottom_left}{line}{bottom_right}")
+            )),
+            Print("\n\r".repeat(self.formatting.bottom_indent as usize + 1))
+        )
+    }
+
+    /// Draws the `title` with the given `style` centered above the `TileMap<T>`, and then draws the map
+    /// to the given `stdout` in the same way as the `draw` method does
+    ///
+    /// The title is centered within the drawn width of the map rows, after the `left_indent`,
+    /// and truncated with `…` if it is wider than the map, in the same way as the header of the `draw_with_header_footer` method.
+    /// If `draw_separator` is `true`, the `─` line of the same width is drawn between the title and the map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(7, 5);
+    /// map.draw_title(&mut stdout(), "Dark Forest", ContentStyle::new().green().bold(), true)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// // 7 tiles, 3 columns wide, with 1 space before each, take 28 columns:
+    /// let lines = |title| {
+    ///     let mut buffer: Vec<u8> = Vec::new();
+    ///     map.draw_title(&mut buffer, title, ContentStyle::new(), true)
+    ///         .expect("should be able to draw to the buffer!");
+    ///     let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    ///     let lines = drawn.split("\n\r").skip(map.formatting.top_indent as usize);
+    ///     lines.take(2).map(String::from).collect::<Vec<String>>()
+    /// };
+    /// let short = lines("Dark Forest");
+    /// assert_eq!(short[0], format!("\t{}Dark Forest", " ".repeat((28 - 11) / 2)));
+    /// assert_eq!(short[1], format!("\t{}", "─".repeat(28)));
+    /// let long = lines("The Dark Forest of the Forgotten Kings");
+    /// assert_eq!(long[0], "\tThe Dark Forest of the Forg…");
+    /// ```
+    pub fn draw_title<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        title: &str,
+        style: ContentStyle,
+        draw_separator: bool,
+    ) -> io::Result<()> {
+        execute!(
+            stdout,
+            Print("\n\r".repeat(self.formatting.top_indent as usize))
+        )?;
+        self.draw_centered_line(stdout, StyledContent::new(style, title))?;
+        if draw_separator {
+            execute!(
+                stdout,
+                Print("\t".repeat(self.formatting.left_indent as usize)),
+                Print("─".repeat(self.row_width() as usize)),
+                Print("\n\r")
+            )?;
+        }
+        let formatting = Formatting {
+            top_indent: 0,
+            ..self.formatting
+        };
+        self.draw_styled(stdout, formatting, |_, tile| tile.tile())
+    }
+
+    /// Draws the optional `header` above the `TileMap<T>` and the optional `footer` below it to the given `stdout`,
+    /// centered within the drawn width of the map rows after the `left_indent`, and the map itself
+    /// in the same way as the `draw` method does
+    ///
+    /// The `top_indent` is drawn before the header, and the `bottom_indent` after the footer.
+    /// If the header or the footer is wider than the map, it is truncated with `…`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Formatting, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Plain;
+    ///
+    /// impl Tile for Plain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".stylize()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(7, 5);
+    /// map.draw_with_header_footer(
+    ///     &mut stdout(),
+    ///     Some("HP: 10/10".red().bold()),
+    ///     Some("Press Q to quit, or any other key to continue the adventure".dark_grey()),
+    /// )
+    /// .expect("should be able to draw to the stdout!");
+    ///
+    /// // the indents go around the header and the footer, and the long header is truncated:
+    /// let formatting = Formatting {
+    ///     row_spacing: 0,
+    ///     top_indent: 2,
+    ///     left_indent: 0,
+    ///     bottom_indent: 1,
+    ///     ..Formatting::default()
+    /// };
+    /// let map: TileMap<Plain> = TileMap::formatted(3, 1, formatting);
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_with_header_footer(&mut buffer, Some("A very long header".stylize()), Some("ok".stylize()))
+    ///     .expect("should be able to draw to the buffer!");
+    /// assert_eq!(
+    ///     String::from_utf8(buffer).expect("should be valid utf-8!"),
+    ///     "\n\r\n\rA very long…\n\r [-] [-] [-]\n\r     ok\n\r\n\r"
+    /// );
+    /// ```
+    pub fn draw_with_header_footer<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        header: Option<StyledContent<&str>>,
+        footer: Option<StyledContent<&str>>,
+    ) -> io::Result<()> {
+        execute!(
+            stdout,
+            Print("\n\r".repeat(self.formatting.top_indent as usize))
+        )?;
+        if let Some(header) = header {
+            self.draw_centered_line(stdout, header)?;
+        }
+        let formatting = Formatting {
+            top_indent: 0,
+            bottom_indent: 0,
+            ..self.formatting
+        };
+        self.draw_styled(stdout, formatting, |_, tile| tile.tile())?;
+        if let Some(footer) = footer {
+            self.draw_centered_line(stdout, footer)?;
+        }
+        execute!(
+            stdout,
+            Print("\n\r".repeat(self.formatting.bottom_indent as usize))
+        )
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` without any spacing, indents and separators,
+    /// ignoring the inner `Formatting`, so every row is drawn as the tiles followed by the newline
+    ///
+    /// Unlike the `draw` method, this doesn't allocate any strings, and flushes the `stdout` only once,
+    /// so it is suitable for the tight render loops
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Formatting, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.compact_draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    ///
+    /// // the same output as the `draw` method with everything set to zero:
+    /// let zero = Formatting {
+    ///     row_spacing: 0,
+    ///     tile_spacing: 0,
+    ///     top_indent: 0,
+    ///     left_indent: 0,
+    ///     bottom_indent: 0,
+    ///     ..Formatting::default()
+    /// };
+    /// let (mut compact, mut drawn) = (Vec::new(), Vec::new());
+    /// map.compact_draw(&mut compact).expect("should be able to draw to the buffer!");
+    /// map.draw_with_formatting_override(&mut drawn, zero)
+    ///     .expect("should be able to draw to the buffer!");
+    /// assert_eq!(compact, drawn);
+    /// let row = "[-]".dark_grey().bold().to_string().repeat(5) + "\n\r";
+    /// assert_eq!(String::from_utf8(compact).expect("should be valid utf-8!"), row.repeat(5));
+    /// ```
+    pub fn compact_draw<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        for row in self.grid().rows() {
+            for cell in row.cells() {
+                queue!(stdout, PrintStyledContent(self.tile_at(cell).tile()))?;
+            }
+            queue!(stdout, Print("\n\r"))?;
+        }
+        stdout.flush()
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` at the center of the terminal window,
+    /// using the inner `Formatting` rules and the cursor movement
+    ///
+    /// The size of the drawn map is calculated from the width of the `T::default()` tile,
+    /// `tile_spacing`, `left_indent` and the number of columns.
+    /// If the map doesn't fit into the terminal window, it is drawn from the top left corner
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the terminal size can't be queried, or if drawing fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.draw_centered(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// ```
+    pub fn draw_centered<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let (width, depth) = self.rendered_size();
+        let origin = if width > columns || depth > rows {
+            (0, 0)
+        } else {
+            ((columns - width) / 2, (rows - depth) / 2)
+        };
+        self.draw_moved(stdout, origin, |_, tile| tile.tile())
+    }
+
+    /// Draws the part of the `TileMap<T>` within the given `viewport` to the given `stdout` with the cursor movement,
+    /// so the first drawn row starts at the given `screen_col` and `screen_row` of the terminal
+    ///
+    /// The `viewport` is clipped to the inner `Grid`, and nothing is drawn if it doesn't overlap the grid.
+    /// The `top_indent`, `left_indent` and `bottom_indent` are ignored, other `Formatting` rules apply,
+    /// and the cursor is left at the end of the last drawn row
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap, Viewport};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(50, 50);
+    /// let viewport = Viewport { top_left: Cell::new(10, 10), width: 8, depth: 4 };
+    /// map.draw_at_with_viewport(&mut stdout(), 20, 2, viewport)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// let draw = |viewport| {
+    ///     let mut buffer: Vec<u8> = Vec::new();
+    ///     map.draw_at_with_viewport(&mut buffer, 20, 2, viewport)
+    ///         .expect("should be able to draw to the buffer!");
+    ///     String::from_utf8(buffer).expect("should be valid utf-8!")
+    /// };
+    /// // every row is moved to its own line, skipping the row spacing:
+    /// let drawn = draw(viewport);
+    /// assert!(["\x1b[3;21H", "\x1b[5;21H", "\x1b[7;21H", "\x1b[9;21H"].iter().all(|row| drawn.contains(row)));
+    /// assert_eq!(drawn.matches("[-]").count(), 32);
+    /// // only the part within the map is drawn:
+    /// let edge = Viewport { top_left: Cell::new(46, 48), ..viewport };
+    /// assert_eq!(draw(edge).matches("[-]").count(), 8);
+    /// let outside = Viewport { top_left: Cell::new(60, 60), ..viewport };
+    /// assert!(draw(outside).is_empty());
+    /// ```
+    pub fn draw_at_with_viewport<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        screen_col: u16,
+        screen_row: u16,
+        viewport: Viewport,
+    ) -> io::Result<()> {
+        if viewport.width == 0 || viewport.depth == 0 {
+            return Ok(());
+        }
+        let top_left = viewport.top_left;
+        let bottom_right = Cell::new(
+            top_left.global_width().saturating_add(viewport.width - 1),
+            top_left.global_depth().saturating_add(viewport.depth - 1),
+        );
+        let Some(region) = self.clamp_region(top_left, bottom_right) else {
+            return Ok(());
+        };
+        let row_step = self.formatting.row_spacing as u16 + 1;
+        for (index, row) in region.rows().enumerate() {
+            queue!(
+                stdout,
+                MoveTo(screen_col, screen_row + index as u16 * row_step)
+            )?;
+            self.queue_row(stdout, self.formatting, row.cells(), &|_, tile: &T| {
+                tile.tile()
+            })?;
+        }
+        stdout.flush()
+    }
+
+    /// Returns a `CellUpdate` command for every cell, where the tile differs from the tile of the `previous` map,
+    /// in the row-major order, using `T::default()` for the vacant cells
+    ///
+    /// Every command moves the cursor to the place, where the `draw` method draws the tile, and prints it,
+    /// so the commands can be executed in a batch, or inspected without a real terminal.
+    /// Only the cells within the inner `Grid` of the current map are compared
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{CellUpdate, Tile, TileMap};
+    /// use crossterm::{queue, style::{Stylize, StyledContent}};
+    /// use grid_math::Cell;
+    /// use std::io::{Write, stdout};
+    ///
+    /// #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let previous: TileMap<Terrain> = TileMap::new(5, 5);
+    /// let mut map = previous.clone();
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// let commands = map.render_diff_to_commands(&previous);
+    /// assert_eq!(commands, vec![CellUpdate { column: 9, row: 4, content: Terrain::Wall.tile() }]);
+    /// for command in commands {
+    ///     queue!(stdout(), command).expect("should be able to draw to the stdout!");
+    /// }
+    /// stdout().flush().expect("should be able to flush the stdout!");
+    /// ```
+    pub fn render_diff_to_commands(&self, previous: &TileMap<T>) -> Vec<CellUpdate>
+    where
+        T: PartialEq,
+    {
+        let cells: HashSet<Cell> = self
+            .keys()
+            .chain(previous.keys().filter(|cell| cell.within(self.grid())))
+            .copied()
+            .collect();
+        let mut cells: Vec<Cell> = cells
+            .into_iter()
+            .filter(|&cell| self.tile_at(cell) != previous.tile_at(cell))
+            .collect();
+        cells.sort_by_key(|cell| (cell.global_depth(), cell.global_width()));
+        cells
+            .into_iter()
+            .map(|cell| {
+                let (column, row) = self.cell_position(cell);
+                CellUpdate {
+                    column,
+                    row,
+                    content: self.tile_at(cell).tile(),
+                }
+            })
+            .collect()
+    }
+
+    /// Draws the progress bar over the already drawn `TileMap<T>` with the cursor movement, replacing `length` tiles
+    /// of the given `row`, starting at the `col_start` column, without modifying the map
+    ///
+    /// The first `fill_ratio` part of the bar tiles is drawn with the `filled_style`, and the rest with the `empty_style`,
+    /// keeping the content of the tiles. The `fill_ratio` is clamped to `0.0..=1.0`.
+    /// Rows and columns are counted from the start of the inner `Grid`, and out of bounds columns are skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(10, 5);
+    /// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// let health = ContentStyle::new().black().on_red();
+    /// let lost = ContentStyle::new().dark_grey().on_black();
+    /// map.draw_progress_bar(&mut stdout(), 0, 1, 8, 0.75, health, lost)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// // returns the number of the filled and the empty tiles of the drawn bar:
+    /// let count = |col_start, fill_ratio| {
+    ///     let mut buffer: Vec<u8> = Vec::new();
+    ///     map.draw_progress_bar(&mut buffer, 0, col_start, 8, fill_ratio, health, lost)
+    ///         .expect("should be able to draw to the buffer!");
+    ///     let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    ///     let filled = health.apply("[-]").to_string();
+    ///     let empty = lost.apply("[-]").to_string();
+    ///     (drawn.matches(&filled).count(), drawn.matches(&empty).count())
+    /// };
+    /// assert_eq!(count(1, 0.0), (0, 8));
+    /// assert_eq!(count(1, 0.5), (4, 4));
+    /// assert_eq!(count(1, 1.0), (8, 0));
+    /// assert_eq!(count(1, 7.0), (8, 0));
+    /// // the columns past the edge of the map are skipped:
+    /// assert_eq!(count(6, 0.5), (4, 0));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_progress_bar<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        row: u8,
+        col_start: u8,
+        length: u8,
+        fill_ratio: f32,
+        filled_style: ContentStyle,
+        empty_style: ContentStyle,
+    ) -> io::Result<()> {
+        let Some(row) = self.grid().rows().nth(row as usize) else {
+            return Ok(());
+        };
+        let filled = (length as f32 * fill_ratio.clamp(0.0, 1.0)).round() as usize;
+        let cells = row.cells().skip(col_start as usize).take(length as usize);
+        for (index, cell) in cells.enumerate() {
+            let style = if index < filled {
+                filled_style
+            } else {
+                empty_style
+            };
+            let (column, line) = self.cell_position(cell);
+            queue!(
+                stdout,
+                MoveTo(column, line),
+                PrintStyledContent(StyledContent::new(
+                    style,
+                    *self.tile_at(cell).tile().content()
+                ))
+            )?;
+        }
+        stdout.flush()
+    }
+
+    /// Draws the given `text` with the given `style` over the already drawn `TileMap<T>` with the cursor movement,
+    /// starting at the place, where the `draw` method draws the tile at the given `cell`, without modifying the map
+    ///
+    /// Multiple calls can build up the text overlay. The `text` is clipped at the end of the drawn row,
+    /// so it never goes past the right edge of the map, and nothing is drawn if the `cell` is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(10, 5);
+    /// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// map.draw_text_overlay(&mut stdout(), Cell::new(2, 1), "Merchant", ContentStyle::new().yellow())
+    ///     .expect("should be able to draw to the stdout!");
+    /// map.draw_text_overlay(&mut stdout(), Cell::new(6, 3), "x3", ContentStyle::new().white().bold())
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// let draw = |cell, text| {
+    ///     let mut buffer: Vec<u8> = Vec::new();
+    ///     map.draw_text_overlay(&mut buffer, cell, text, ContentStyle::new())
+    ///         .expect("should be able to draw to the buffer!");
+    ///     String::from_utf8(buffer).expect("should be valid utf-8!")
+    /// };
+    /// // the last tile of the row starts at the column 45, and takes 3 columns:
+    /// assert_eq!(draw(Cell::new(9, 1), "Merchant"), "\x1b[7;46HMer");
+    /// assert_eq!(draw(Cell::new(2, 1), "Merchant"), "\x1b[7;18HMerchant");
+    /// assert!(draw(Cell::new(10, 1), "Merchant").is_empty());
+    /// ```
+    pub fn draw_text_overlay<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        cell: Cell,
+        text: &str,
+        style: ContentStyle,
+    ) -> io::Result<()> {
+        if !cell.within(self.grid()) {
+            return Ok(());
+        }
+        let (column, row) = self.cell_position(cell);
+        let last = Cell::new(self.grid().end().global_width(), cell.global_depth());
+        let row_end = self.cell_position(last).0 + self.tile_width();
+        let text: String = text.chars().take((row_end - column) as usize).collect();
+        queue!(
+            stdout,
+            MoveTo(column, row),
+            PrintStyledContent(StyledContent::new(style, text))
+        )?;
+        stdout.flush()
+    }
+
+    /// Draws the `background`, `midground` and `foreground` maps to the given `stdout` on top of each other
+    /// with the cursor movement, starting from the top left corner of the terminal
+    ///
+    /// The whole `background` is drawn first, and then the explicitly inserted tiles of the `midground`
+    /// and the `foreground` overwrite the tiles below them, and the cursor is moved below the drawn map
+    ///
+    /// # Errors
+    /// Returns `io::Error` of the `InvalidInput` kind, without drawing anything, if the inner `Grid` of the `midground`
+    /// or the `foreground` is not equal to the inner `Grid` of the `background`, or if their `Formatting` is not equal
+    /// to the `Formatting` of the `background`, and returns any `io::Error` of the drawing itself
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Grass;
+    ///
+    /// impl Tile for Grass {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[\"]".green()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Item;
+    ///
+    /// impl Tile for Item {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[*]".yellow()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Hero;
+    ///
+    /// impl Tile for Hero {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[&]".cyan().bold()
+    ///     }
+    /// }
+    ///
+    /// let background: TileMap<Grass> = TileMap::new(5, 5);
+    /// let mut items: TileMap<Item> = TileMap::new(5, 5);
+    /// items.insert(Cell::new(1, 1), Item);
+    /// let mut heroes: TileMap<Hero> = TileMap::new(5, 5);
+    /// heroes.insert(Cell::new(3, 2), Hero);
+    /// TileMap::draw_with_z_order(&background, &items, &heroes, &mut stdout())
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// // replays the drawn bytes onto a blank screen, skipping the colors:
+    /// fn screen(drawn: &[u8]) -> Vec<String> {
+    ///     let drawn = String::from_utf8(drawn.to_vec()).expect("should be valid utf-8!");
+    ///     let (mut screen, mut x, mut y) = (vec![vec![' '; 80]; 24], 0, 0);
+    ///     let mut chars = drawn.chars();
+    ///     while let Some(char) = chars.next() {
+    ///         match char {
+    ///             '\x1b' => {
+    ///                 let mut sequence = String::new();
+    ///                 let end = chars.by_ref().skip(1).find(|c| {
+    ///                     sequence.push(*c);
+    ///                     c.is_ascii_alphabetic()
+    ///                 });
+    ///                 if end == Some('H') {
+    ///                     let (row, column) = sequence.trim_end_matches('H').split_once(';')
+    ///                         .expect("should be a cursor move!");
+    ///                     y = row.parse::<usize>().expect("should be a row!") - 1;
+    ///                     x = column.parse::<usize>().expect("should be a column!") - 1;
+    ///                 }
+    ///             }
+    ///             '\n' => y += 1,
+    ///             '\r' => x = 0,
+    ///             _ => {
+    ///                 screen[y][x] = char;
+    ///                 x += 1;
+    ///             }
+    ///         }
+    ///     }
+    ///     screen
+    ///         .iter()
+    ///         .map(|row| row.iter().collect::<String>().trim().to_owned())
+    ///         .filter(|row| !row.is_empty())
+    ///         .collect()
+    /// }
+    ///
+    /// // the item is covered by the hero at (1, 1), and the grass is covered by the item at (2, 2):
+    /// items.insert(Cell::new(2, 2), Item);
+    /// heroes.insert(Cell::new(1, 1), Hero);
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// TileMap::draw_with_z_order(&background, &items, &heroes, &mut buffer)
+    ///     .expect("should be able to draw to the buffer!");
+    /// let screen = screen(&buffer);
+    /// assert_eq!(screen[1], r#"["] [&] ["] ["] ["]"#);
+    /// assert_eq!(screen[2], r#"["] ["] [*] [&] ["]"#);
+    ///
+    /// // both the different grids and the different formatting are rejected:
+    /// let moved: TileMap<Hero> = TileMap::new(3, 3);
+    /// let mut spaced: TileMap<Hero> = TileMap::new(5, 5);
+    /// spaced.formatting.tile_spacing = 3;
+    /// for foreground in [moved, spaced] {
+    ///     let e = TileMap::draw_with_z_order(&background, &items, &foreground, &mut Vec::new()).unwrap_err();
+    ///     assert_eq!(e.kind(), std::io::ErrorKind::InvalidInput);
+    /// }
+    /// ```
+    pub fn draw_with_z_order<W, T2, T3>(
+        background: &TileMap<T>,
+        midground: &TileMap<T2>,
+        foreground: &TileMap<T3>,
+        stdout: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+        T2: Tile + Default,
+        T3: Tile + Default,
+    {
+        let grid = background.grid();
+        let formatting = background.formatting;
+        for layer in [midground.grid(), foreground.grid()] {
+            if layer != grid {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "can't draw layers with different grids! background:{grid}, layer:{layer}"
+                    ),
+                ));
+            }
+        }
+        if midground.formatting != formatting || foreground.formatting != formatting {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "can't draw layers with different formatting!",
+            ));
+        }
+        background.draw_moved(stdout, (0, 0), |_, tile| tile.tile())?;
+        let layers = midground
+            .iter()
+            .map(|(cell, tile)| (*cell, tile.tile()))
+            .chain(foreground.iter().map(|(cell, tile)| (*cell, tile.tile())));
+        for (cell, tile) in layers {
+            let (column, row) = background.cell_position(cell);
+            queue!(stdout, MoveTo(column, row), PrintStyledContent(tile))?;
+        }
+        queue!(stdout, MoveTo(0, background.rendered_size().1))?;
+        stdout.flush()
+    }
+
+    /// Draws the `TileMap<T>` to the given async `stdout` in the same way as the `draw` method does,
+    /// without blocking the async executor
+    ///
+    /// The whole map is rendered to the buffer first, so the map itself is never borrowed across the `.await` point,
+    /// and then written in the same way as the `AsyncDraw::resume` method does.
+    /// To continue the drawing after the returned future is dropped, for example in the `tokio::select!` loop,
+    /// keep the `AsyncDraw` and call its `resume` method instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let map: TileMap<Empty> = TileMap::new(5, 5);
+    ///     map.draw_async(&mut tokio::io::stdout())
+    ///         .await
+    ///         .expect("should be able to draw to the stdout!");
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn draw_async<W>(&self, stdout: &mut W) -> io::Result<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        AsyncDraw::new(self).resume(stdout).await
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but with the rows in the reversed order, so the last row is drawn first
+    ///
+    /// This flips the drawn map vertically, without changing the map itself
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// // the hero is drawn in the bottom left corner:
+    /// map.insert(Cell::new(0, 0), Entity::Hero);
+    /// map.draw_inverted(&mut stdout()).expect("should be able to draw to the stdout!");
+    ///
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_inverted(&mut buffer).expect("should be able to draw to the buffer!");
+    /// let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    /// let rows: Vec<&str> = drawn.split("\n\r").filter(|line| line.contains('[')).collect();
+    /// assert_eq!(rows.len(), 5);
+    /// assert_eq!(rows.iter().position(|row| row.contains("[&]")), Some(4));
+    /// assert!(rows[4].find("[&]") < rows[4].find("[-]"));
+    /// ```
+    pub fn draw_inverted<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        let mut rows: Vec<Grid> = self.grid().rows().collect();
+        rows.reverse();
+        self.draw_rows(
+            stdout,
+            self.formatting,
+            rows.into_iter().map(Grid::cells),
+            |_, tile| tile.tile(),
+        )
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but with the tiles of every row in the reversed order, so the last column is drawn first
+    ///
+    /// This flips the drawn map horizontally, without changing the map itself.
+    /// Combined with the `draw_inverted` method, all four reflections of the map can be drawn
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// // the hero is drawn in the top right corner:
+    /// map.insert(Cell::new(0, 0), Entity::Hero);
+    /// map.draw_mirrored(&mut stdout()).expect("should be able to draw to the stdout!");
+    ///
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_mirrored(&mut buffer).expect("should be able to draw to the buffer!");
+    /// let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    /// let rows: Vec<&str> = drawn.split("\n\r").filter(|line| line.contains('[')).collect();
+    /// assert_eq!(rows.len(), 5);
+    /// assert_eq!(rows.iter().position(|row| row.contains("[&]")), Some(0));
+    /// assert!(rows[0].rfind("[&]") > rows[0].rfind("[-]"));
+    /// ```
+    pub fn draw_mirrored<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        let rows = self.grid().rows().map(|row| {
+            let mut cells: Vec<Cell> = row.cells().collect();
+            cells.reverse();
+            cells
+        });
+        self.draw_rows(stdout, self.formatting, rows, |_, tile| tile.tile())
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// with the dimmed header of the column numbers above the map, and the dimmed row number before every row
+    ///
+    /// The numbers are the global coordinates of the cells, so they match the `Cell`s used to access the map.
+    /// Column numbers are centered within the width of the tile, and row numbers are aligned to the right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Formatting, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Plain;
+    ///
+    /// impl Tile for Plain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".stylize()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(10, 10);
+    /// map.draw_grid_coords(&mut stdout()).expect("should be able to draw to the stdout!");
+    ///
+    /// // numbers stay above their tiles with the separators:
+    /// let formatting = Formatting {
+    ///     row_spacing: 0,
+    ///     top_indent: 0,
+    ///     left_indent: 0,
+    ///     bottom_indent: 0,
+    ///     tile_separator: Some("|".stylize()),
+    ///     border_tiles: true,
+    ///     ..Formatting::default()
+    /// };
+    /// let map: TileMap<Plain> = TileMap::formatted(3, 2, formatting);
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_grid_coords(&mut buffer).expect("should be able to draw to the buffer!");
+    /// let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    /// assert!(drawn.contains("    0     1     2 "));
+    /// assert_eq!(drawn.matches("| [-] | [-] | [-] |\n\r").count(), 2);
+    /// ```
+    pub fn draw_grid_coords<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        let grid = self.grid();
+        let label_width = grid.end().global_depth().to_string().len();
+        let tile_width = self.tile_width() as usize;
+        let indent = "\t".repeat(self.formatting.left_indent as usize);
+        let indent_width = self.formatting.left_indent as usize * TAB_WIDTH as usize;
+        // column numbers are placed at the same columns as the tiles, shifted by the row labels:
+        let mut header = " ".repeat(label_width);
+        for cell in grid
+            .rows()
+            .next()
+            .expect("grid should have at least one row!")
+            .cells()
+        {
+            let column = self.cell_position(cell).0 as usize - indent_width + label_width;
+            let padding = column.saturating_sub(header.len());
+            header.push_str(&" ".repeat(padding));
+            header.push_str(&format!("{:^tile_width$}", cell.global_width()));
+        }
+        queue!(
+            stdout,
+            Print("\n\r".repeat(self.formatting.top_indent as usize)),
+            Print(&indent),
+            PrintStyledContent(header.dim()),
+            Print("\n\r")
+        )?;
+        for row in grid.rows() {
+            let number = format!("{:>label_width$}", row.start().global_depth());
+            queue!(
+                stdout,
+                Print("\n\r".repeat(self.formatting.row_spacing as usize)),
+                Print(&indent),
+                PrintStyledContent(number.dim())
+            )?;
+            self.queue_row(stdout, self.formatting, row.cells(), &|_, tile: &T| {
+                tile.tile()
+            })?;
+            queue!(
+                stdout,
+                Print(" ".repeat(self.formatting.right_indent as usize)),
+                Print("\n\r")
+            )?;
+        }
+        queue!(
+            stdout,
+            Print("\n\r".repeat(self.formatting.bottom_indent as usize))
+        )?;
+        stdout.flush()
+    }
+
+    /// Draws only the tiles of the given `row` from the `col_start` to the `col_end` column inclusively,
+    /// to the given `stdout` in-line, without any newlines and indents
+    ///
+    /// Rows and columns are counted from the start of the inner `Grid`.
+    /// The `tile_spacing` and the `tile_separator` are still drawn before every tile.
+    /// Out of bounds columns are skipped, and nothing is drawn if the `row` is out of bounds,
+    /// or if the `col_end` is less than the `col_start`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(10, 5);
+    /// map.draw_partial_row(&mut stdout(), 2, 3, 7)
+    ///     .expect("should be able to draw to the stdout!");
+    ///
+    /// let draw = |row, col_start, col_end| {
+    ///     let mut buffer: Vec<u8> = Vec::new();
+    ///     map.draw_partial_row(&mut buffer, row, col_start, col_end)
+    ///         .expect("should be able to draw to the buffer!");
+    ///     String::from_utf8(buffer).expect("should be valid utf-8!")
+    /// };
+    /// let tile = "[-]".dark_grey().bold().to_string();
+    /// assert_eq!(draw(2, 3, 7), format!(" {tile}").repeat(5));
+    /// // the columns past the edge are skipped:
+    /// assert_eq!(draw(2, 8, 20), format!(" {tile}").repeat(2));
+    /// assert!(draw(5, 3, 7).is_empty());
+    /// assert!(draw(2, 10, 12).is_empty());
+    /// assert!(draw(2, 7, 3).is_empty());
+    /// ```
+    pub fn draw_partial_row<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        row: u8,
+        col_start: u8,
+        col_end: u8,
+    ) -> io::Result<()> {
+        let Some(row) = self.grid().rows().nth(row as usize) else {
+            return Ok(());
+        };
+        let cells = row
+            .cells()
+            .skip(col_start as usize)
+            .take((col_end as usize + 1).saturating_sub(col_start as usize));
+        let formatting = Formatting {
+            border_tiles: false,
+            ..self.formatting
+        };
+        self.queue_row(stdout, formatting, cells, &|_, tile: &T| tile.tile())?;
+        stdout.flush()
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` in the same way as the `draw` method does,
+    /// but every row is prefixed with its dimmed zero-based index and the `" |"` separator, after the `left_indent`
+    ///
+    /// Indices are counted from the start of the inner `Grid`, and aligned to the right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 12);
+    /// map.draw_with_row_numbers(&mut stdout()).expect("should be able to draw to the stdout!");
+    ///
+    /// // labels are as wide as the biggest index:
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_with_row_numbers(&mut buffer).expect("should be able to draw to the buffer!");
+    /// let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    /// assert!(drawn.contains(" 0 |") && drawn.contains("11 |"));
+    /// let map: TileMap<Empty> = TileMap::new(5, 10);
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_with_row_numbers(&mut buffer).expect("should be able to draw to the buffer!");
+    /// let drawn = String::from_utf8(buffer).expect("should be valid utf-8!");
+    /// assert!(!drawn.contains(" 0 |") && drawn.contains("9 |"));
+    /// ```
+    pub fn draw_with_row_numbers<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        let label_width = (self.grid().depth() - 1).to_string().len();
+        execute!(
+            stdout,
+            Print("\n\r".repeat(self.formatting.top_indent as usize))
+        )?;
+        for (index, row) in self.grid().rows().enumerate() {
+            let label = format!("{index:>label_width$} |");
+            execute!(
+                stdout,
+                Print("\n\r".repeat(self.formatting.row_spacing as usize)),
+                Print("\t".repeat(self.formatting.left_indent as usize)),
+                PrintStyledContent(label.dim())
+            )?;
+            self.queue_row(stdout, self.formatting, row.cells(), &|_, tile: &T| {
+                tile.tile()
+            })?;
+            execute!(
+                stdout,
+                Print(" ".repeat(self.formatting.right_indent as usize)),
+                Print("\n\r")
+            )?;
+        }
+        execute!(
+            stdout,
+            Print("\n\r".repeat(self.formatting.bottom_indent as usize))
+        )
+    }
+
+    /// Draws the horizontal `─` line with the given `style` to the given `stdout`, after the `left_indent` tabs,
+    /// exactly as wide as the drawn row of the map of the given `width`, `tile_width` and `tile_spacing`
+    ///
+    /// Useful for separating the maps, drawn one after another.
+    /// The line is `width * (tile_spacing + tile_width)` columns wide, so the `tile_separator` and the `border_tiles`
+    /// are not taken into account, and the line is narrower than the rows of the maps, drawn with the separators
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{ContentStyle, Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let level: TileMap<Empty> = TileMap::new(7, 5);
+    /// level.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// TileMap::<Empty>::draw_separating_line(&mut stdout(), 7, 3, 1, 1, ContentStyle::new().dark_grey())
+    ///     .expect("should be able to draw to the stdout!");
+    /// level.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    ///
+    /// // 7 tiles, 3 columns wide, with 1 space before each, take 28 columns:
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// TileMap::<Empty>::draw_separating_line(&mut buffer, 7, 3, 1, 1, ContentStyle::new())
+    ///     .expect("should be able to draw to the buffer!");
+    /// assert_eq!(String::from_utf8(buffer).expect("should be valid utf-8!"), format!("\t{}\n\r", "─".repeat(28)));
+    /// ```
+    pub fn draw_separating_line<W: io::Write>(
+        stdout: &mut W,
+        width: u8,
+        tile_width: u8,
+        tile_spacing: u8,
+        left_indent: u8,
+        style: ContentStyle,
+    ) -> io::Result<()> {
+        let line = "─".repeat(width as usize * (tile_spacing as usize + tile_width as usize));
+        execute!(
+            stdout,
+            Print("\t".repeat(left_indent as usize)),
+            PrintStyledContent(StyledContent::new(style, line)),
+            Print("\n\r")
+        )
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` from the top left corner of the terminal window,
+    /// tile by tile in the column-major order, using the inner `Formatting` rules and the cursor movement
+    ///
+    /// Every tile is moved to the same position, as the `draw` method would draw it at,
+    /// so the final picture is the same, and only the drawing order differs.
+    /// After drawing, the cursor is moved below the map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use grid_math::Cell;
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default, PartialEq)]
+    /// enum Entity {
+    ///     #[default]
+    ///     Empty,
+    ///     Hero,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Entity::Empty => "[-]".stylize(),
+    ///             Entity::Hero => "[&]".stylize(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.draw_columns_first(&mut stdout()).expect("should be able to draw to the stdout!");
+    ///
+    /// // replays the drawn bytes onto a blank screen, to compare the final pictures:
+    /// fn screen(drawn: &[u8]) -> Vec<String> {
+    ///     let drawn = String::from_utf8(drawn.to_vec()).expect("should be valid utf-8!");
+    ///     let (mut screen, mut x, mut y) = (vec![vec![' '; 80]; 24], 0, 0);
+    ///     let mut chars = drawn.chars();
+    ///     while let Some(char) = chars.next() {
+    ///         match char {
+    ///             '\x1b' => {
+    ///                 let sequence: String = chars.by_ref().skip(1).take_while(|c| *c != 'H').collect();
+    ///                 let (row, column) = sequence.split_once(';').expect("should be a cursor move!");
+    ///                 y = row.parse::<usize>().expect("should be a row!") - 1;
+    ///                 x = column.parse::<usize>().expect("should be a column!") - 1;
+    ///             }
+    ///             '\n' => y += 1,
+    ///             '\r' => x = 0,
+    ///             '\t' => x = (x / 8 + 1) * 8,
+    ///             _ => {
+    ///                 screen[y][x] = char;
+    ///                 x += 1;
+    ///             }
+    ///         }
+    ///     }
+    ///     screen.iter().map(|row| row.iter().collect::<String>().trim_end().to_owned()).collect()
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(4, 3);
+    /// map.insert(Cell::new(2, 1), Entity::Hero);
+    /// let (mut rows_first, mut columns_first) = (Vec::new(), Vec::new());
+    /// map.draw(&mut rows_first).expect("should be able to draw to the buffer!");
+    /// map.draw_columns_first(&mut columns_first).expect("should be able to draw to the buffer!");
+    /// assert_eq!(screen(&columns_first), screen(&rows_first));
+    /// assert!(screen(&columns_first).iter().any(|row| row.contains("[-] [-] [&] [-]")));
+    /// ```
+    pub fn draw_columns_first<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        for column in self.grid().columns() {
+            for cell in column.cells() {
+                let (x, y) = self.cell_position(cell);
+                queue!(
+                    stdout,
+                    MoveTo(x, y),
+                    PrintStyledContent(self.tile_at(cell).tile())
+                )?;
+            }
+        }
+        queue!(stdout, MoveTo(0, self.rendered_size().1))?;
+        stdout.flush()
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout`, using the given `Formatting` rules,
+    /// and the given `style` closure to get the styled content of every tile
+    fn draw_styled<W, F>(&self, stdout: &mut W, formatting: Formatting, style: F) -> io::Result<()>
+    where
+        W: io::Write,
+        F: Fn(Cell, &T) -> StyledContent<&'static str>,
+    {
+        self.draw_rows(
+            stdout,
+            formatting,
+            self.grid().rows().map(Grid::cells),
+            style,
+        )
+    }
+
+    /// Draws the given `rows` of cells to the given `stdout` in the given order, using the given `Formatting` rules,
+    /// and the given `style` closure to get the styled content of every tile
+    fn draw_rows<W, R, C, F>(
+        &self,
+        stdout: &mut W,
+        formatting: Formatting,
+        rows: R,
+        style: F,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Cell>,
+        F: Fn(Cell, &T) -> StyledContent<&'static str>,
+    {
+        execute!(stdout, Print("\n\r".repeat(formatting.top_indent as usize)))?;
+        for row in rows {
+            execute!(
+                stdout,
+                Print("\n\r".repeat(formatting.row_spacing as usize)),
+                Print("\t".repeat(formatting.left_indent as usize))
+            )?;
+            self.queue_row(stdout, formatting, row, &style)?;
+            execute!(
+                stdout,
+                Print(" ".repeat(formatting.right_indent as usize)),
+                Print("\n\r")
+            )?;
+        }
+        execute!(
+            stdout,
+            Print("\n\r".repeat(formatting.bottom_indent as usize))
+        )?;
+        Ok(())
+    }
+
+    /// Returns the raw bytes, including the `ANSI` escape sequences,
+    /// that the `draw` method would write to the `stdout`, using the inner `Formatting` rules
+    ///
+    /// Useful for sending the drawn map over the network, writing it to a file, and testing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let buffer = map.render_to_buffer();
+    /// let mut drawn = Vec::new();
+    /// map.draw(&mut drawn).expect("should be able to draw to the buffer!");
+    /// assert_eq!(buffer, drawn);
+    /// ```
+    pub fn render_to_buffer(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.draw(&mut buffer)
+            .expect("writing to a buffer should not fail!");
+        buffer
+    }
+
+    /// Returns the `TileMap<T>` exported as the `HTML` table, where every row is a `<tr>`,
+    /// and every tile is a `<td>`, containing the `<span>` with the raw symbol of the tile
+    ///
+    /// Foreground and background colors of the tiles are translated to the `CSS` `color` and `background-color`,
+    /// and the bold, italic and underlined attributes are translated to the matching `CSS` properties.
+    /// Left padding of the cells matches the `tile_spacing` of the inner `Formatting`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().italic(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(2, 1);
+    /// map.insert(Cell::new(1, 0), Entity::Hero);
+    /// assert_eq!(
+    ///     map.as_html(),
+    ///     "<table style=\"border-collapse: collapse; font-family: monospace\">\n\
+    ///     <tr>\
+    ///     <td style=\"padding: 0 0 0 1ch\"><span style=\"color: #808080; font-weight: bold\">[-]</span></td>\
+    ///     <td style=\"padding: 0 0 0 1ch\"><span style=\"color: #00ff00; font-style: italic\">[&amp;]</span></td>\
+    ///     </tr>\n\
+    ///     </table>\n"
+    /// );
+    /// ```
+    pub fn as_html(&self) -> String {
+        let mut html =
+            String::from("<table style=\"border-collapse: collapse; font-family: monospace\">\n");
+        let padding = format!("padding: 0 0 0 {}ch", self.formatting.tile_spacing);
+        for row in self.grid().rows() {
+            html.push_str("<tr>");
+            for cell in row.cells() {
+                let tile = self.tile_at(cell).tile();
+                let style = tile.style();
+                let mut css = Vec::new();
+                if let Some(color) = style.foreground_color.and_then(css_color) {
+                    css.push(format!("color: {color}"));
+                }
+                if let Some(color) = style.background_color.and_then(css_color) {
+                    css.push(format!("background-color: {color}"));
+                }
+                if style.attributes.has(Attribute::Bold) {
+                    css.push(String::from("font-weight: bold"));
+                }
+                if style.attributes.has(Attribute::Italic) {
+                    css.push(String::from("font-style: italic"));
+                }
+                if style.attributes.has(Attribute::Underlined) {
+                    css.push(String::from("text-decoration: underline"));
+                }
+                let span = if css.is_empty() {
+                    String::from("<span>")
+                } else {
+                    format!("<span style=\"{}\">", css.join("; "))
+                };
+                let symbol = tile
+                    .content()
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;");
+                html.push_str(&format!(
+                    "<td style=\"{padding}\">{span}{symbol}</span></td>"
+                ));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</table>\n");
+        html
+    }
+
+    /// Prints the debug view of the `TileMap<T>` to the `stderr`, intended only for debugging
+    ///
+    /// The view contains the `Dimensions` and the `Grid` of the map, the number of the explicitly inserted tiles,
+    /// the inner `Formatting`, and the raw symbols of the tiles with the global column and row numbers.
+    /// Tiles, which symbol is not 3 characters wide, are marked with `!`, since they break the alignment
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             // will be marked with `!`:
+    ///             Self::Hero => "&".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 2), Entity::Hero);
+    /// map.debug_draw();
+    /// ```
+    pub fn debug_draw(&self) {
+        let grid = self.grid();
+        let dimensions = self.dimensions();
+        let label_width = grid.end().global_depth().to_string().len();
+        eprintln!(
+            "TileMap {}x{} at {grid}, {} occupied cells",
+            dimensions.width,
+            dimensions.depth,
+            self.len()
+        );
+        eprintln!("{:?}", self.formatting);
+        let header: String = grid
+            .rows()
+            .next()
+            .expect("grid should have at least one row!")
+            .cells()
+            .map(|cell| format!(" {:^3}", cell.global_width()))
+            .collect();
+        eprintln!("{:label_width$}{header}", "");
+        for row in grid.rows() {
+            let tiles: String = row
+                .cells()
+                .map(|cell| {
+                    let tile = self.tile_at(cell).tile();
+                    if tile.content().chars().count() == 3 {
+                        format!(" {}", tile.content())
+                    } else {
+                        String::from("  ! ")
+                    }
+                })
+                .collect();
+            eprintln!("{:>label_width$}{tiles}", row.start().global_depth());
+        }
+    }
+
+    /// Writes the statistics of the `TileMap<T>` to the given `out`, as a table of the total area of the inner `Grid`,
+    /// the number of the explicitly inserted tiles, and the number of the vacant cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 2), Empty);
+    /// let mut out = Vec::new();
+    /// map.print_statistics(&mut out).expect("should be able to write to the buffer!");
+    /// assert_eq!(String::from_utf8(out).unwrap(), "area          25\ninserted       1\nvacant        24\n");
+    /// ```
+    pub fn print_statistics<W: io::Write>(&self, out: &mut W) -> io::Result<()> {
+        let area = self.dimensions().area();
+        writeln!(out, "{:<10}{:>6}", "area", area)?;
+        writeln!(out, "{:<10}{:>6}", "inserted", self.len())?;
+        writeln!(out, "{:<10}{:>6}", "vacant", area - self.len())
+    }
+
+    /// Formats the `TileMap<T>` as a plain string, in the same way as the `Display` implementation does,
+    /// but without any `ANSI` escape sequences, so only the raw symbols of the tiles are written
+    ///
+    /// Useful for logging, writing to files, and testing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap, Formatting};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let formatting = Formatting {
+    ///     row_spacing: 0,
+    ///     tile_spacing: 1,
+    ///     top_indent: 0,
+    ///     left_indent: 0,
+    ///     right_indent: 0,
+    ///     bottom_indent: 0,
+    ///     tile_separator: None,
+    ///     border_tiles: false,
+    ///     reset_after_each_tile: false,
+    /// };
+    /// let mut map: TileMap<Entity> = TileMap::formatted(3, 2, formatting);
+    /// map.insert(Cell::new(1, 0), Entity::Hero);
+    /// assert_eq!(map.to_plain_string(), " [-] [&] [-]\n\r [-] [-] [-]\n\r");
+    /// map.formatting.tile_separator = Some("|".white());
+    /// map.formatting.border_tiles = true;
+    /// assert_eq!(map.to_plain_string(), "| [-] | [&] | [-] |\n\r| [-] | [-] | [-] |\n\r");
+    /// ```
+    pub fn to_plain_string(&self) -> String {
+        let mut plain = String::new();
+        self.write_tiles(
+            &mut plain,
+            self.grid(),
+            |_, tile| tile.symbol(),
+            |separator| *separator.content(),
+        )
+        .expect("writing to a string should not fail!");
+        plain
+    }
+
+    /// Writes the `TileMap<T>` to the given `fmt::Formatter` in the same way as the `Display` implementation does,
+    /// but only the part of the map within the given `viewport` rectangle from the top left
+    /// to the bottom right cell, or the whole map if the `viewport` is `None`
+    ///
+    /// The `viewport` is clamped to the inner `Grid`, and nothing is written if it doesn't overlap the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Formatting, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::fmt::{self, Display};
+    ///
+    /// #[derive(Default, PartialEq)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey(),
+    ///             Self::Hero => "[&]".green(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// struct Corner<'a>(&'a TileMap<Entity>);
+    ///
+    /// impl Display for Corner<'_> {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         self.0.write_to_fmt(f, Some((Cell::new(0, 0), Cell::new(1, 1))))
+    ///     }
+    /// }
+    ///
+    /// let formatting = Formatting {
+    ///     row_spacing: 0,
+    ///     tile_spacing: 0,
+    ///     top_indent: 0,
+    ///     left_indent: 0,
+    ///     bottom_indent: 0,
+    ///     ..Formatting::default()
+    /// };
+    /// let mut map: TileMap<Entity> = TileMap::formatted(5, 5, formatting);
+    /// map.insert(Cell::new(1, 1), Entity::Hero);
+    /// let corner = Corner(&map).to_string();
+    /// assert!(corner.contains("[&]"));
+    /// assert_eq!(corner.matches("\n\r").count(), 2);
+    /// ```
+    pub fn write_to_fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        viewport: Option<(Cell, Cell)>,
+    ) -> fmt::Result {
+        let region = match viewport {
+            Some((top_left, bottom_right)) => match self.clamp_region(top_left, bottom_right) {
+                Some(region) => region,
+                None => return Ok(()),
+            },
+            None => self.grid(),
+        };
+        self.write_tiles(f, region, |_, tile| tile.tile(), |separator| separator)
+    }
+
+    /// Writes the given `region` of the `TileMap<T>` to the given `fmt::Write`, using the inner `Formatting` rules,
+    /// and the given `content` and `separator` closures to get the displayed content of every tile and separator
+    fn write_tiles<W, F, S, D>(
+        &self,
+        f: &mut W,
+        region: Grid,
+        content: F,
+        separator: S,
+    ) -> fmt::Result
+    where
+        W: fmt::Write,
+        F: Fn(Cell, &T) -> D,
+        S: Fn(StyledContent<&'static str>) -> D,
+        D: Display,
+    {
+        let spacing = " ".repeat(self.formatting.tile_spacing as usize);
+        write!(f, "{}", "\n\r".repeat(self.formatting.top_indent as usize))?;
+        for row in region.rows() {
+            write!(f, "{}", "\n\r".repeat(self.formatting.row_spacing as usize))?;
+            write!(f, "{}", "\t".repeat(self.formatting.left_indent as usize))?;
+            if let Some(border) = self.formatting.border_separator() {
+                write!(f, "{}", separator(border))?;
+            }
+            for (index, cell) in row.cells().enumerate() {
+                if index > 0
+                    && let Some(between) = self.formatting.tile_separator
+                {
+                    write!(f, "{spacing}{}", separator(between))?;
+                }
+                write!(f, "{spacing}{}", content(cell, self.tile_at(cell)))?;
+            }
+            if let Some(border) = self.formatting.border_separator() {
+                write!(f, "{spacing}{}", separator(border))?;
+            }
+            write!(f, "{}", " ".repeat(self.formatting.right_indent as usize))?;
+            write!(f, "\n\r")?;
+        }
+        write!(
+            f,
+            "{}",
+            "\n\r".repeat(self.formatting.bottom_indent as usize)
+        )?;
+        Ok(())
+    }
+
+    /// Writes the next generation of the cellular automaton with the given `rule` to the empty `scratch` map,
+    /// storing only the non-default tiles
+    fn automaton_step_into<F>(&self, rule: &F, scratch: &mut GridMap<T>)
+    where
+        T: PartialEq,
+        F: Fn(Cell, &T, [Option<&T>; 8]) -> T,
+    {
+        for cell in self.grid().cells() {
+            let neighbors = self
+                .neighbors8(cell)
+                .map(|neighbor| neighbor.map(|(_, tile)| tile));
+            let tile = rule(cell, self.tile_at(cell), neighbors);
+            if tile != self.default {
+                scratch.insert(cell, tile);
+            }
+        }
+    }
+
+    /// Returns ref to the tile at the given `cell`, or to the `T::default()` tile if the cell is vacant
+    fn tile_at(&self, cell: Cell) -> &T {
+        self.get(&cell).unwrap_or(&self.default)
+    }
+
+    /// Sets every tile of the given row or column `Grid` from the `tiles` slice,
+    /// removing the tiles equal to `T::default()` from the map
+    fn set_cells(&mut self, cells: Grid, tiles: &[T]) -> Result<(), RowError>
+    where
+        T: Clone + PartialEq,
+    {
+        let expected = cells.size() as u8;
+        if tiles.len() != expected as usize {
+            return Err(RowError::LengthMismatch {
+                expected,
+                found: tiles.len(),
+            });
+        }
+        for (cell, tile) in cells.cells().zip(tiles) {
+            if *tile == self.default {
+                self.remove(&cell);
+            } else {
+                self.insert(cell, tile.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the part of the rectangle from `top_left` to `bottom_right`, that is within the inner `Grid`,
+    /// or `None` if the rectangle doesn't overlap the grid
+    fn clamp_region(&self, top_left: Cell, bottom_right: Cell) -> Option<Grid> {
+        let (start, end) = (self.grid().start(), self.grid().end());
+        let top_left = Cell::new(
+            top_left.global_width().max(start.global_width()),
+            top_left.global_depth().max(start.global_depth()),
+        );
+        let bottom_right = Cell::new(
+            bottom_right.global_width().min(end.global_width()),
+            bottom_right.global_depth().min(end.global_depth()),
+        );
+        (top_left.global_width() <= bottom_right.global_width()
+            && top_left.global_depth() <= bottom_right.global_depth())
+        .then(|| Grid::from((top_left, bottom_right)))
+    }
+
+    /// Replaces the inner `Grid` with the given one, removing the tiles that are not within it
+    fn set_grid(&mut self, grid: Grid) {
+        let mut hashmap = std::mem::take(&mut *self.gridmap);
+        hashmap.retain(|cell, _| cell.within(grid));
+        self.gridmap = GridMap::from((grid, hashmap));
+    }
+
+    /// Returns the `cell`, moved by the given `width` and `depth` offsets,
+    /// or `None` if the moved cell is not within the inner `Grid`
+    fn offset(&self, cell: Cell, width: i16, depth: i16) -> Option<Cell> {
+        let moved = Cell::new(
+            u8::try_from(cell.global_width() as i16 + width).ok()?,
+            u8::try_from(cell.global_depth() as i16 + depth).ok()?,
+        );
+        moved.within(self.grid()).then_some(moved)
+    }
+
+    /// Draws the `TileMap<T>` to the given `stdout` with the cursor movement, so the tiles end up
+    /// at the same places as if the `draw` method started drawing at the given `origin`,
+    /// and then moves the cursor below the drawn map
+    fn draw_moved<W, F>(&self, stdout: &mut W, origin: (u16, u16), style: F) -> io::Result<()>
+    where
+        W: io::Write,
+        F: Fn(Cell, &T) -> StyledContent<&'static str>,
+    {
+        let row_start = self.formatting.tile_spacing as u16
+            + self
+                .formatting
+                .border_separator()
+                .map_or(0, |_| self.formatting.separator_width());
+        for row in self.grid().rows() {
+            let (column, line) = self.cell_position(row.start());
+            queue!(
+                stdout,
+                MoveTo(origin.0 + column - row_start, origin.1 + line)
+            )?;
+            self.queue_row(stdout, self.formatting, row.cells(), &style)?;
+            queue!(
+                stdout,
+                Print(" ".repeat(self.formatting.right_indent as usize))
+            )?;
+        }
+        queue!(stdout, MoveTo(0, origin.1 + self.rendered_size().1))?;
+        stdout.flush()
+    }
+
+    /// Draws the given `line` to the given `stdout` after the `left_indent`, centered within the drawn width of the map rows,
+    /// and truncated with `…` if it is wider than the map
+    fn draw_centered_line<W: io::Write>(
+        &self,
+        stdout: &mut W,
+        line: StyledContent<&str>,
+    ) -> io::Result<()> {
+        let row_width = self.row_width() as usize;
+        let count = line.content().chars().count();
+        let (text, padding) = if count > row_width {
+            let truncated: String = line
+                .content()
+                .chars()
+                .take(row_width.saturating_sub(1))
+                .chain(['…'])
+                .collect();
+            (truncated, 0)
+        } else {
+            (line.content().to_string(), (row_width - count) / 2)
+        };
+        execute!(
+            stdout,
+            Print("\t".repeat(self.formatting.left_indent as usize)),
+            Print(" ".repeat(padding)),
+            PrintStyledContent(StyledContent::new(*line.style(), text)),
+            Print("\n\r")
+        )
+    }
+
+    /// Queues the tiles of the given `row` to the given `stdout` after the `left_indent`, using the given `Formatting` rules,
+    /// and the given `style` closure to get the styled content of every tile
+    fn queue_row<W, C, F>(
+        &self,
+        stdout: &mut W,
+        formatting: Formatting,
+        row: C,
+        style: &F,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+        C: IntoIterator<Item = Cell>,
+        F: Fn(Cell, &T) -> StyledContent<&'static str>,
+    {
+        let spacing = " ".repeat(formatting.tile_spacing as usize);
+        if let Some(border) = formatting.border_separator() {
+            queue!(stdout, PrintStyledContent(border))?;
+        }
+        for (index, cell) in row.into_iter().enumerate() {
+            if index > 0
+                && let Some(separator) = formatting.tile_separator
+            {
+                queue!(stdout, Print(&spacing), PrintStyledContent(separator))?;
+            }
+            queue!(
+                stdout,
+                Print(&spacing),
+                PrintStyledContent(style(cell, self.tile_at(cell)))
+            )?;
+            if formatting.reset_after_each_tile {
+                queue!(stdout, ResetColor)?;
+            }
+        }
+        if let Some(border) = formatting.border_separator() {
+            queue!(stdout, Print(&spacing), PrintStyledContent(border))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the width of the drawn row of the `TileMap<T>` in terminal columns,
+    /// not including the `left_indent`
+    fn row_width(&self) -> u16 {
+        let width = self.grid().width() as u16;
+        let tile_spacing = self.formatting.tile_spacing as u16;
+        let border = self
+            .formatting
+            .border_separator()
+            .map_or(0, |_| 2 * self.formatting.separator_width() + tile_spacing);
+        width * (tile_spacing + self.tile_width()) + (width - 1) * self.separator_step() + border
+    }
+
+    /// Returns the number of terminal columns, taken by the `tile_separator` and its spaces between two tiles
+    fn separator_step(&self) -> u16 {
+        self.formatting.tile_separator.map_or(0, |_| {
+            self.formatting.tile_spacing as u16 + self.formatting.separator_width()
+        })
+    }
+
+    /// Returns the `(columns, rows)` size of the drawn `TileMap<T>` in the terminal,
+    /// including the indents of the inner `Formatting`
+    fn rendered_size(&self) -> (u16, u16) {
+        let grid = self.grid();
+        let width = self.formatting.left_indent as u16 * TAB_WIDTH
+            + self.row_width()
+            + self.formatting.right_indent as u16;
+        let depth = self.formatting.top_indent as u16
+            + grid.depth() as u16 * (self.formatting.row_spacing as u16 + 1)
+            + self.formatting.bottom_indent as u16;
+        (width, depth)
+    }
+
+    /// Returns the width of the drawn tile in terminal columns, measured on the `T::default()` tile
+    fn tile_width(&self) -> u16 {
+        T::default().tile().content().chars().count() as u16
+    }
+
+    /// Returns the terminal `(column, row)` position of the given `cell` relative to the point,
+    /// from which the `draw` method starts drawing the `TileMap<T>`, using the inner `Formatting` rules
+    ///
+    /// Every tab of the `left_indent` is counted as `TAB_WIDTH` columns wide
+    fn cell_position(&self, cell: Cell) -> (u16, u16) {
+        let grid = self.grid();
+        let (width, depth) = (cell.width(grid) as u16, cell.depth(grid) as u16);
+        let tile_spacing = self.formatting.tile_spacing as u16;
+        let row_spacing = self.formatting.row_spacing as u16;
+        let border = self
+            .formatting
+            .border_separator()
+            .map_or(0, |_| self.formatting.separator_width());
+        let column = self.formatting.left_indent as u16 * TAB_WIDTH
+            + border
+            + tile_spacing
+            + width * (tile_spacing + self.tile_width() + self.separator_step());
+        let row = self.formatting.top_indent as u16 + row_spacing + depth * (row_spacing + 1);
+        (column, row)
+    }
+}
+
+impl<T> TileMap<T>
+where
+    T: Tile + Default + TileLegend + PartialEq,
+{
+    /// Draws the legend of the `TileMap<T>` to the given `stdout`, as a two-column list of
+    /// `symbol` and `description` pairs, one for every unique tile in the map, in the row-major order
+    ///
+    /// Vacant cells are counted as `T::default()` tiles.
+    /// Meant to be called right after the `draw` method, so the legend is printed below the map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileLegend, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use std::io::stdout;
+    ///
+    /// #[derive(Default, PartialEq)]
+    /// enum Entity {
+    ///     Hero,
+    ///     Goblin,
+    ///     Orc,
+    ///     Door(bool),
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///             Self::Goblin => "[@]".green(),
+    ///             Self::Orc => "[@]".red(),
+    ///             Self::Door(true) => "[/]".yellow(),
+    ///             Self::Door(false) => "[+]".yellow(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl TileLegend for Entity {
+    ///     fn legend_entry(&self) -> (&'static str, &'static str) {
+    ///         match self {
+    ///             Self::Air => ("[-]", "air"),
+    ///             Self::Hero => ("[&]", "hero"),
+    ///             Self::Goblin | Self::Orc => ("[@]", "enemy"),
+    ///             Self::Door(_) => ("[/+]", "door"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 0), Entity::Hero);
+    /// map.draw(&mut stdout()).expect("should be able to draw to the stdout!");
+    /// map.draw_legend(&mut stdout()).expect("should be able to draw to the stdout!");
+    ///
+    /// // the enemies and the doors share their entries, and the symbols are padded to the widest one:
+    /// map.insert(Cell::new(2, 0), Entity::Goblin);
+    /// map.insert(Cell::new(3, 0), Entity::Door(true));
+    /// map.insert(Cell::new(4, 0), Entity::Orc);
+    /// map.insert(Cell::new(0, 1), Entity::Door(false));
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// map.draw_legend(&mut buffer).expect("should be able to draw to the buffer!");
+    /// assert_eq!(
+    ///     String::from_utf8(buffer).expect("should be valid utf-8!"),
+    ///     "\t [-]  - air\n\r\t [&]  - hero\n\r\t [@]  - enemy\n\r\t [/+] - door\n\r"
+    /// );
+    /// ```
+    pub fn draw_legend<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        let mut tiles: Vec<&T> = Vec::new();
+        for cell in self.grid().cells() {
+            let tile = self.tile_at(cell);
+            if !tiles.contains(&tile) {
+                tiles.push(tile);
+            }
+        }
+        let mut entries: Vec<(&'static str, &'static str)> = Vec::new();
+        for entry in tiles.iter().map(|tile| tile.legend_entry()) {
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+        let symbol_width = entries
+            .iter()
+            .map(|(symbol, _)| symbol.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (symbol, description) in entries {
+            execute!(
+                stdout,
+                Print("\t".repeat(self.formatting.left_indent as usize)),
+                Print(" ".repeat(self.formatting.tile_spacing as usize)),
+                Print(format!("{symbol:<symbol_width$} - {description}")),
+                Print("\n\r")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> TileMap<T>
+where
+    T: Tile + Default + TileCodec,
+{
+    /// Encodes the `TileMap<T>` as `CSV`, with one line for every row, and with comma-separated codes of the tiles,
+    /// using `T::default()` for the vacant cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{ParseError, Tile, TileCodec, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Terrain {
+    ///     Wall,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Terrain {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Wall => "[#]".white().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl TileCodec for Terrain {
+    ///     fn code(&self) -> &str {
+    ///         match self {
+    ///             Self::Wall => "#",
+    ///             Self::Floor => ".",
+    ///         }
+    ///     }
+    ///
+    ///     fn from_code(s: &str) -> Option<Self> {
+    ///         match s {
+    ///             "#" => Some(Self::Wall),
+    ///             "." => Some(Self::Floor),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Terrain> = TileMap::new(3, 2);
+    /// map.insert(Cell::new(0, 0), Terrain::Wall);
+    /// map.insert(Cell::new(2, 1), Terrain::Wall);
+    /// let csv = map.to_csv();
+    /// assert_eq!(csv, "#,.,.\n.,.,#\n");
+    /// let parsed: TileMap<Terrain> = TileMap::from_csv(&csv, 3, 2).expect("csv should be valid!");
+    /// assert_eq!(parsed.to_csv(), csv);
+    /// // short rows and missing rows are filled with default tiles:
+    /// let parsed: TileMap<Terrain> = TileMap::from_csv("#\n", 3, 2).expect("csv should be valid!");
+    /// assert_eq!(parsed.to_csv(), "#,.,.\n.,.,.\n");
+    /// assert_eq!(TileMap::<Terrain>::from_csv("", 0, 3).unwrap_err(), ParseError::EmptySize);
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in self.grid().rows() {
+            let codes: Vec<&str> = row.cells().map(|cell| self.tile_at(cell).code()).collect();
+            csv.push_str(&codes.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Decodes new `TileMap<T>` of specified size from the `CSV`, with one line for every row,
+    /// and with comma-separated codes of the tiles, inserting only the non-default tiles
+    ///
+    /// Codes are trimmed, and empty codes, short lines, and missing lines are treated as `T::default()` tiles
+    ///
+    /// # Errors
+    /// Returns `ParseError` if some code is unknown, if there are more lines or codes than the size of the map,
+    /// or if `width` or `depth` < 1
+    ///
+    /// For examples, see the `to_csv` method
+    ///
+    pub fn from_csv(s: &str, width: u8, depth: u8) -> Result<TileMap<T>, ParseError>
+    where
+        T: PartialEq,
+    {
+        if width < 1 || depth < 1 {
+            return Err(ParseError::EmptySize);
+        }
+        let mut map = TileMap::new(width, depth);
+        for (line, row) in s.lines().enumerate() {
+            for (column, code) in row.split(',').map(str::trim).enumerate() {
+                if code.is_empty() {
+                    continue;
+                }
+                if line >= depth as usize || column >= width as usize {
+                    return Err(ParseError::OutOfBounds { line, column });
+                }
+                let tile = T::from_code(code).ok_or_else(|| ParseError::UnknownCode {
+                    line,
+                    column,
+                    code: code.to_string(),
+                })?;
+                if tile != map.default {
+                    map.insert(Cell::new(column as u8, line as u8), tile);
+                }
+            }
+        }
+        Ok(map)
+    }
+}
+
+impl<T> TileMap<T>
+where
+    T: Tile + Default + TileCategory,
+{
+    /// Writes the statistics of the `TileMap<T>` to the given `out` in the same way as the `print_statistics` method does,
+    /// followed by the number of cells in every category, using `T::default()` for the vacant cells
+    ///
+    /// Categories are sorted by the number of cells, biggest first, and then by name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileCategory, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Goblin,
+    ///     Orc,
+    ///     #[default]
+    ///     Floor,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Floor => "[-]".dark_grey().bold(),
+    ///             Self::Goblin => "[g]".green().bold(),
+    ///             Self::Orc => "[O]".red().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl TileCategory for Entity {
+    ///     fn category_name(&self) -> &'static str {
+    ///         match self {
+    ///             Self::Goblin | Self::Orc => "enemy",
+    ///             Self::Floor => "floor",
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(3, 3);
+    /// map.insert(Cell::new(0, 0), Entity::Goblin);
+    /// map.insert(Cell::new(2, 2), Entity::Orc);
+    /// let mut out = Vec::new();
+    /// map.print_category_statistics(&mut out).expect("should be able to write to the buffer!");
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "area           9\ninserted       2\nvacant         7\n\nfloor          7\nenemy          2\n"
+    /// );
+    /// ```
+    pub fn print_category_statistics<W: io::Write>(&self, out: &mut W) -> io::Result<()> {
+        self.print_statistics(out)?;
+        let mut counts: HashMap<&'static str, usize> = HashMap::new();
+        for cell in self.grid().cells() {
+            *counts
+                .entry(self.tile_at(cell).category_name())
+                .or_default() += 1;
+        }
+        let mut counts: Vec<(&'static str, usize)> = counts.into_iter().collect();
+        counts.sort_by(|(a_name, a_count), (b_name, b_count)| {
+            b_count.cmp(a_count).then(a_name.cmp(b_name))
+        });
+        writeln!(out)?;
+        for (name, count) in counts {
+            writeln!(out, "{name:<10}{count:>6}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Hand-editable `RON` representation of the `TileMap<T>`, used by the `TileMap::to_ron` and `TileMap::from_ron` methods
+#[cfg(feature = "ron")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "TileMap")]
+struct RonTileMap<T> {
+    width: u8,
+    depth: u8,
+    tiles: Vec<((u8, u8), T)>,
+}
+
+/// `TileMap<T>`, validated while deserializing from the `RonTileMap<T>`
+#[cfg(feature = "ron")]
+#[derive(serde::Deserialize)]
+#[serde(try_from = "RonTileMap<T>")]
+struct RonChecked<T: Tile + Default + PartialEq>(TileMap<T>);
+
+#[cfg(feature = "ron")]
+impl<T> TryFrom<RonTileMap<T>> for RonChecked<T>
+where
+    T: Tile + Default + PartialEq,
+{
+    type Error = String;
+
+    fn try_from(ron: RonTileMap<T>) -> Result<Self, Self::Error> {
+        if ron.width == 0 || ron.depth == 0 {
+            return Err(DimensionError::Empty.to_string());
+        }
+        let mut map = TileMap::new(ron.width, ron.depth);
+        for ((width, depth), tile) in ron.tiles {
+            let cell = Cell::new(width, depth);
+            if !cell.within(map.grid()) {
+                return Err(format!("tile at {cell} is out of bounds!"));
+            }
+            if tile != map.default {
+                map.insert(cell, tile);
+            }
+        }
+        Ok(Self(map))
+    }
+}
+
+#[cfg(feature = "ron")]
+impl<T> TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Serializes the `TileMap<T>` to the pretty `RON` string, as the `TileMap` struct
+    /// with the `width`, the `depth`, and the `tiles` list of the explicitly inserted tiles in the row-major order
+    ///
+    /// Every tile is written as the `((width, depth), tile)` pair, with the cell relative to the start of the inner `Grid`.
+    /// `Formatting` is not serialized
+    ///
+    /// # Errors
+    /// Returns `ron::Error` if any tile can't be serialized
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+    /// enum Entity {
+    ///     Enemy,
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///             Self::Enemy => "[@]".red().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 4);
+    /// map.insert(Cell::new(3, 3), Entity::Enemy);
+    /// map.insert(Cell::new(1, 0), Entity::Hero);
+    /// let ron = map.to_ron().expect("should be able to serialize the map!");
+    /// assert!(ron.contains("width: 5") && ron.contains("depth: 4"));
+    /// assert!(ron.contains("((1, 0), Hero)") && ron.contains("((3, 3), Enemy)"));
+    /// let parsed: TileMap<Entity> = TileMap::from_ron(&ron).expect("should be able to parse the map!");
+    /// assert_eq!(parsed.to_plain_string(), map.to_plain_string());
+    ///
+    /// let edited = "TileMap(width: 3, depth: 1, tiles: [((2, 0), Hero)])";
+    /// let parsed: TileMap<Entity> = TileMap::from_ron(edited).expect("should be able to parse the map!");
+    /// assert_eq!(parsed.get(&Cell::new(2, 0)), Some(&Entity::Hero));
+    /// assert!(TileMap::<Entity>::from_ron("TileMap(width: 3, depth: 1, tiles: [((3, 0), Hero)])").is_err());
+    /// ```
+    pub fn to_ron(&self) -> ron::Result<String>
+    where
+        T: serde::Serialize,
+    {
+        let grid = self.grid();
+        let ron = RonTileMap {
+            width: grid.width(),
+            depth: grid.depth(),
+            tiles: self
+                .into_iter()
+                .map(|(cell, tile)| ((cell.width(grid), cell.depth(grid)), tile))
+                .collect(),
+        };
+        ron::ser::to_string_pretty(&ron, ron::ser::PrettyConfig::default())
+    }
+
+    /// Deserializes the `TileMap<T>` from the `RON` string, written in the same format as the `to_ron` method writes,
+    /// with the default `Formatting`, and inserting only the non-default tiles
+    ///
+    /// # Errors
+    /// Returns `ron::error::SpannedError` if the string is not valid, if the `width` or the `depth` is zero,
+    /// or if any tile is out of bounds
+    ///
+    /// For examples, see the `to_ron` method
+    ///
+    pub fn from_ron(s: &str) -> ron::error::SpannedResult<TileMap<T>>
+    where
+        T: serde::de::DeserializeOwned + PartialEq,
+    {
+        ron::from_str::<RonChecked<T>>(s).map(|checked| checked.0)
+    }
+}
+
+impl<T> Display for TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Implements `fmt` method for the `TileMap<T>` in the same way as the `draw` method works
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
+    /// println!("{map}");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_tiles(f, self.grid(), |_, tile| tile.tile(), |separator| separator)
+    }
+}
+
+impl<T> fmt::Debug for TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Implements `fmt` method for the `TileMap<T>`, showing its `Dimensions`, `Formatting`,
+    /// and the inserted tiles as a list of `(cell, symbol)` pairs in the row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Default)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey(),
+    ///             Self::Hero => "[&]".green(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(3, 2);
+    /// map.insert(Cell::new(1, 0), Entity::Hero);
+    /// let debug = format!("{map:?}");
+    /// assert!(debug.starts_with("TileMap { dimensions: Dimensions { width: 3, depth: 2 }"));
+    /// assert!(debug.contains("\"[&]\""));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tiles: Vec<(Cell, &'static str)> = self
+            .gridmap
+            .iter()
+            .map(|(cell, tile)| (*cell, tile.symbol()))
+            .collect();
+        tiles.sort_by_key(|(cell, _)| (cell.global_depth(), cell.global_width()));
+        f.debug_struct("TileMap")
+            .field("dimensions", &self.dimensions())
+            .field("formatting", &self.formatting)
+            .field("tiles", &tiles)
+            .finish()
+    }
+}
+
+impl<T> Default for TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Creates new empty 1x1 `TileMap<T>` with the default `Formatting`
+    ///
+    /// The `Grid` can not be smaller than 1x1, so this is the smallest possible `TileMap<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Dimensions, Formatting, Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let map: TileMap<Empty> = TileMap::default();
+    /// assert_eq!(map.dimensions(), Dimensions { width: 1, depth: 1 });
+    /// assert_eq!(map.formatting, Formatting::default());
+    /// assert!(map.is_empty());
+    /// ```
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+impl<T> From<Grid> for TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Creates new empty `TileMap<T>` from the specified `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// #[derive(Default)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let cells = (Cell::new(2, 2), Cell::new(5, 5));
+    /// let grid = Grid::from(cells);
+    /// let map: TileMap<Empty> = TileMap::from(grid);
+    /// assert_eq!(map.grid(), grid);
+    /// ```
+    fn from(grid: Grid) -> Self {
+        Self {
+            formatting: Formatting::default(),
+            gridmap: GridMap::from(grid),
+            default: T::default(),
+        }
+    }
+}
+
+impl<T> From<GridMap<T>> for TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Creates `TileMap<T>` from the existing `GridMap<T>` where `T`: `Tile` + `Default`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut gridmap: GridMap<Empty> = GridMap::new(5, 5);
+    /// let target = Cell::new(1, 2);
+    /// gridmap.insert(target, Empty);
+    /// let map: TileMap<Empty> = TileMap::from(gridmap);
+    /// assert_eq!(map.get(&target), Some(&Empty));
+    /// ```
+    fn from(gridmap: GridMap<T>) -> Self {
+        Self {
+            formatting: Formatting::default(),
+            gridmap,
+            default: T::default(),
+        }
+    }
+}
+
+impl<T> From<(Grid, HashMap<Cell, T>)> for TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Creates `TileMap<T>` from the existing `HashMap<Cell, T>` and the given `Grid`
+    ///
+    /// # Panics
+    /// Panics if the given `HashMap<Cell, T>` contains `Cell`s that are not within the given `Grid`,
+    /// use the `try_from_hashmap` method to get the `OutOfBoundsError` instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let mut hashmap: HashMap<Cell, Empty> = HashMap::new();
+    /// let target = Cell::new(1, 2);
+    /// hashmap.insert(target, Empty);
+    /// let map: TileMap<Empty> = TileMap::from((grid, hashmap));
+    /// assert_eq!(map.get(&target), Some(&Empty));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let mut hashmap: HashMap<Cell, Empty> = HashMap::new();
+    /// let target = Cell::new(7, 1);
+    /// hashmap.insert(target, Empty);
+    /// let map: TileMap<Empty> = TileMap::from((grid, hashmap)); // panic!
+    /// ```
+    fn from(data: (Grid, HashMap<Cell, T>)) -> Self {
+        Self {
+            formatting: Formatting::default(),
+            gridmap: GridMap::from(data),
+            default: T::default(),
+        }
+    }
+}
+
+impl<T> IntoIterator for TileMap<T>
+where
+    T: Tile + Default,
+{
+    type Item = (Cell, T);
+    type IntoIter = std::vec::IntoIter<(Cell, T)>;
+
+    /// Consumes the `TileMap<T>`, and returns iterator over the explicitly inserted tiles
+    /// with their cells in the row-major order, in the same way as the `drain` method does
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// struct Empty;
+    ///
+    /// impl Tile for Empty {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         "[-]".dark_grey().bold()
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Empty> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(4, 1), Empty);
+    /// map.insert(Cell::new(2, 1), Empty);
+    /// let cells: Vec<Cell> = map.into_iter().map(|(cell, _)| cell).collect();
+    /// assert_eq!(cells, vec![Cell::new(2, 1), Cell::new(4, 1)]);
+    /// ```
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.drain().collect::<Vec<_>>().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a TileMap<T>
+where
+    T: Tile + Default,
+{
+    type Item = (Cell, &'a T);
+    type IntoIter = std::vec::IntoIter<(Cell, &'a T)>;
+
+    /// Returns iterator over the explicitly inserted tiles with their cells in the row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Enemy,
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///             Self::Enemy => "[@]".red().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(3, 3), Entity::Hero);
+    /// map.insert(Cell::new(1, 0), Entity::Hero);
+    /// for (_, tile) in &mut map {
+    ///     *tile = Entity::Enemy;
+    /// }
+    /// let tiles: Vec<(Cell, &Entity)> = (&map).into_iter().collect();
+    /// assert_eq!(tiles, vec![(Cell::new(1, 0), &Entity::Enemy), (Cell::new(3, 3), &Entity::Enemy)]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let mut tiles: Vec<(Cell, &'a T)> = self.iter().map(|(&cell, tile)| (cell, tile)).collect();
+        tiles.sort_by_key(|(cell, _)| (cell.global_depth(), cell.global_width()));
+        tiles.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut TileMap<T>
+where
+    T: Tile + Default,
+{
+    type Item = (Cell, &'a mut T);
+    type IntoIter = std::vec::IntoIter<(Cell, &'a mut T)>;
+
+    /// Returns iterator over the explicitly inserted tiles as mut refs with their cells in the row-major order
+    ///
+    /// For examples, see the `IntoIterator` implementation for `&TileMap<T>`
+    ///
+    fn into_iter(self) -> Self::IntoIter {
+        let mut tiles: Vec<(Cell, &'a mut T)> =
+            self.iter_mut().map(|(&cell, tile)| (cell, tile)).collect();
+        tiles.sort_by_key(|(cell, _)| (cell.global_depth(), cell.global_width()));
+        tiles.into_iter()
+    }
+}
+
+/// Returns the given `tile` with the given `style` applied on top of its own style,
+/// so the colors, set in the `style`, replace the colors of the tile, and the attributes of both are combined
+fn overlay(style: ContentStyle, tile: StyledContent<&'static str>) -> StyledContent<&'static str> {
+    let own = *tile.style();
+    let style = ContentStyle {
+        foreground_color: style.foreground_color.or(own.foreground_color),
+        background_color: style.background_color.or(own.background_color),
+        underline_color: style.underline_color.or(own.underline_color),
+        attributes: own.attributes | style.attributes,
+    };
+    StyledContent::new(style, *tile.content())
+}
+
+/// Returns the `CSS` hex color, matching the given terminal `Color`, or `None` for the `Color::Reset`
+///
+/// Named colors and the first 16 `ANSI` values are translated to the `VGA`-like palette,
+/// and the rest of the `ANSI` values are translated to the `xterm` 256 colors palette
+fn css_color(color: Color) -> Option<String> {
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (128, 0, 0),
+        (0, 128, 0),
+        (128, 128, 0),
+        (0, 0, 128),
+        (128, 0, 128),
+        (0, 128, 128),
+        (192, 192, 192),
+        (128, 128, 128),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (0, 0, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    let (r, g, b) = match color {
+        Color::Reset => return None,
+        Color::Black => PALETTE[0],
+        Color::DarkRed => PALETTE[1],
+        Color::DarkGreen => PALETTE[2],
+        Color::DarkYellow => PALETTE[3],
+        Color::DarkBlue => PALETTE[4],
+        Color::DarkMagenta => PALETTE[5],
+        Color::DarkCyan => PALETTE[6],
+        Color::Grey => PALETTE[7],
+        Color::DarkGrey => PALETTE[8],
+        Color::Red => PALETTE[9],
+        Color::Green => PALETTE[10],
+        Color::Yellow => PALETTE[11],
+        Color::Blue => PALETTE[12],
+        Color::Magenta => PALETTE[13],
+        Color::Cyan => PALETTE[14],
+        Color::White => PALETTE[15],
+        Color::Rgb { r, g, b } => (r, g, b),
+        Color::AnsiValue(value @ 0..16) => PALETTE[value as usize],
+        Color::AnsiValue(value @ 16..232) => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let value = value - 16;
+            (level(value / 36), level(value / 6 % 6), level(value % 6))
+        }
+        Color::AnsiValue(value) => {
+            let level = 8 + (value - 232) * 10;
+            (level, level, level)
+        }
+    };
+    Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+/// `RowRef<'a, T>` represents a borrowed row of the `TileMap<T>`, returned by the `TileMap::row_iter` method
+///
+/// For examples, see the `TileMap::row_iter` method
+///
+#[derive(Debug)]
+pub struct RowRef<'a, T>
+where
+    T: Tile + Default,
+{
+    map: &'a TileMap<T>,
+    index: u8,
+    cells: Grid,
+}
+
+impl<'a, T> RowRef<'a, T>
+where
+    T: Tile + Default,
+{
+    /// Returns the index of the row, relative to the start of the inner `Grid`
+    ///
+    pub fn row_index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns an iterator over every cell of the row and its tile, from left to right,
+    /// using `T::default()` for the vacant cells
+    ///
+    pub fn cells(&self) -> impl Iterator<Item = (Cell, &'a T)> + use<'a, T> {
+        let map = self.map;
+        self.cells
+            .cells()
+            .map(move |cell| (cell, map.tile_at(cell)))
+    }
+}
+
+/// `ColumnRef<'a, T>` represents a borrowed column of the `TileMap<T>`, returned by the `TileMap::col_iter` method
+///
+/// For examples, see the `TileMap::col_iter` method
+///
+#[derive(Debug)]
+pub struct ColumnRef<'a, T>
+where
+    T: Tile + Default,
+{
+    map: &'a TileMap<T>,
+    index: u8,
+    cells: Grid,
+}
+
+impl<'a, T> ColumnRef<'a, T>
+where
+    T: Tile + Default,
+{
+    /// Returns the index of the column, relative to the start of the inner `Grid`
+    ///
+    pub fn column_index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns an iterator over every cell of the column and its tile, from top to bottom,
+    /// using `T::default()` for the vacant cells
+    ///
+    pub fn cells(&self) -> impl Iterator<Item = (Cell, &'a T)> + use<'a, T> {
+        let map = self.map;
+        self.cells
+            .cells()
+            .map(move |cell| (cell, map.tile_at(cell)))
+    }
+}
+
+/// `TileMapView<'a, T>` represents a read-only view of the `TileMap<T>`, returned by the `TileMap::freeze` method
+///
+/// The view exposes only the methods, that don't change the map, so it can be passed to the drawing code
+/// with the guarantee, that the map stays the same
+///
+/// For examples, see the `TileMap::freeze` method
+///
+#[derive(Debug)]
+pub struct TileMapView<'a, T>
+where
+    T: Tile + Default,
+{
+    map: &'a TileMap<T>,
+}
+
+impl<'a, T> TileMapView<'a, T>
+where
+    T: Tile + Default,
+{
+    /// Returns the inner `Grid` of the viewed map
     ///
-    /// # Examples
+    pub fn grid(&self) -> Grid {
+        self.map.grid()
+    }
+
+    /// Returns the `Dimensions` of the viewed map
     ///
-    /// ```
-    /// use cli_tilemap::{Tile, TileMap};
-    /// use crossterm::style::{Stylize, StyledContent};
-    /// use grid_math::{Cell, Grid};
+    pub fn dimensions(&self) -> Dimensions {
+        self.map.dimensions()
+    }
+
+    /// Returns the `Formatting` of the viewed map
     ///
-    /// #[derive(Default)]
-    /// struct Empty;
+    pub fn formatting(&self) -> Formatting {
+        self.map.formatting
+    }
+
+    /// Returns ref to the explicitly inserted tile at the given `cell`, or `None` if the cell is vacant
     ///
-    /// impl Tile for Empty {
-    ///     fn tile(&self) -> StyledContent<&'static str> {
-    ///         "[-]".dark_grey().bold()
-    ///     }
-    /// }
+    pub fn get(&self, cell: &Cell) -> Option<&'a T> {
+        self.map.get(cell)
+    }
+
+    /// Returns the number of the explicitly inserted tiles
     ///
-    /// let cells = (Cell::new(2, 2), Cell::new(5, 5));
-    /// let grid = Grid::from(cells);
-    /// let map: TileMap<Empty> = TileMap::from(grid);
-    /// assert_eq!(map.grid(), grid);
-    /// ```
-    fn from(grid: Grid) -> Self {
-        Self {
-            formatting: Formatting::default(),
-            gridmap: GridMap::from(grid),
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the viewed map has no explicitly inserted tiles
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over the explicitly inserted tiles and their cells in arbitrary order
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&'a Cell, &'a T)> + use<'a, T> {
+        self.map.gridmap.iter()
+    }
+
+    /// Draws the viewed map to the given `stdout` in the same way as the `TileMap::draw` method does
+    ///
+    pub fn draw<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        self.map.draw(stdout)
+    }
+
+    /// Formats the viewed map as a plain string in the same way as the `TileMap::to_plain_string` method does
+    ///
+    pub fn to_plain_string(&self) -> String {
+        self.map.to_plain_string()
+    }
+}
+
+impl<T> Clone for TileMapView<'_, T>
+where
+    T: Tile + Default,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TileMapView<'_, T> where T: Tile + Default {}
+
+impl<T> Display for TileMapView<'_, T>
+where
+    T: Tile + Default,
+{
+    /// Implements `fmt` method for the `TileMapView<'a, T>` in the same way as for the viewed `TileMap<T>`
+    ///
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.map, f)
+    }
+}
+
+/// `TileMapSlice<'a, T>` represents a borrowed rectangular region of the `TileMap<T>`, returned by the `TileMap::slice` method
+///
+/// Cells of the slice are relative to its top left corner, so the slice starts at (0,0).
+/// No tiles are copied
+///
+/// For examples, see the `TileMap::slice` method
+///
+#[derive(Debug)]
+pub struct TileMapSlice<'a, T>
+where
+    T: Tile + Default,
+{
+    map: &'a TileMap<T>,
+    top_left: Cell,
+    width: u8,
+    depth: u8,
+}
+
+impl<'a, T> TileMapSlice<'a, T>
+where
+    T: Tile + Default,
+{
+    /// Returns the `Dimensions` of the slice
+    ///
+    pub fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            width: self.width,
+            depth: self.depth,
         }
     }
+
+    /// Returns ref to the tile at the given `cell` of the slice, or ref to the default tile if the cell is vacant
+    ///
+    /// # Panics
+    /// Panics if the given `cell` is not within the slice
+    ///
+    pub fn get(&self, cell: Cell) -> &'a T {
+        let cell = Cell::new(
+            self.top_left.global_width() + cell.global_width(),
+            self.top_left.global_depth() + cell.global_depth(),
+        );
+        cell.within_panic(self.region());
+        self.map.tile_at(cell)
+    }
+
+    /// Returns an iterator over every cell of the slice and its tile in the row-major order,
+    /// using `T::default()` for the vacant cells
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (Cell, &'a T)> + use<'a, T> {
+        let (map, region) = (self.map, self.region());
+        region.cells().map(move |cell| {
+            (
+                Cell::new(cell.width(region), cell.depth(region)),
+                map.tile_at(cell),
+            )
+        })
+    }
+
+    /// Draws the slice to the given `stdout` in the same way as the `TileMap::draw` method does,
+    /// using the `Formatting` of the sliced map
+    ///
+    pub fn draw<W: io::Write>(&self, stdout: &mut W) -> io::Result<()> {
+        self.map.draw_rows(
+            stdout,
+            self.map.formatting,
+            self.region().rows().map(Grid::cells),
+            |_, tile| tile.tile(),
+        )
+    }
+
+    /// Returns the region of the sliced map, covered by the slice
+    fn region(&self) -> Grid {
+        let bottom_right = Cell::new(
+            self.top_left.global_width() + self.width - 1,
+            self.top_left.global_depth() + self.depth - 1,
+        );
+        Grid::from((self.top_left, bottom_right))
+    }
 }
 
-impl<T> From<GridMap<T>> for TileMap<T>
+/// `DoubleBuffer<T>` represents a pair of `TileMap<T>` buffers, used for the flicker-free drawing
+///
+/// The next frame is written to the `back` buffer, and then the `swap_and_draw` method
+/// draws only the tiles, that differ from the `front` buffer, which holds the previously drawn frame.
+/// Tiles are positioned with the cursor movement, starting at the top left corner of the terminal
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::{DoubleBuffer, Tile, TileMap};
+/// use crossterm::style::{Stylize, StyledContent};
+/// use grid_math::Cell;
+/// use std::io::stdout;
+///
+/// #[derive(Default, Clone)]
+/// enum Entity {
+///     Hero,
+///     #[default]
+///     Air,
+/// }
+///
+/// impl Tile for Entity {
+///     fn tile(&self) -> StyledContent<&'static str> {
+///         match self {
+///             Self::Air => "[-]".dark_grey().bold(),
+///             Self::Hero => "[&]".green().bold(),
+///         }
+///     }
+/// }
+///
+/// let mut buffer: DoubleBuffer<Entity> = DoubleBuffer::new(5, 5);
+/// buffer.back_mut().insert(Cell::new(0, 0), Entity::Hero);
+/// // first frame is drawn completely:
+/// buffer.swap_and_draw(&mut stdout()).expect("should be able to draw to the stdout!");
+/// // move hero, only two tiles will be redrawn:
+/// buffer.back_mut().move_tile(Cell::new(0, 0), Cell::new(1, 0));
+/// buffer.swap_and_draw(&mut stdout()).expect("should be able to draw to the stdout!");
+///
+/// // every redrawn tile is moved to, while the full redraw moves to every row and below the map:
+/// let mut drawn: Vec<u8> = Vec::new();
+/// let moves = |drawn: &[u8]| String::from_utf8_lossy(drawn).matches('H').count();
+/// buffer.back_mut().move_tile(Cell::new(1, 0), Cell::new(2, 0));
+/// buffer.swap_and_draw(&mut drawn).expect("should be able to draw to the buffer!");
+/// assert_eq!(moves(&drawn), 2);
+/// assert_eq!(String::from_utf8_lossy(&drawn).matches("[-]").count(), 1);
+/// // changed formatting falls back to the full redraw:
+/// drawn.clear();
+/// buffer.back_mut().formatting.tile_spacing = 2;
+/// buffer.swap_and_draw(&mut drawn).expect("should be able to draw to the buffer!");
+/// assert_eq!(moves(&drawn), 6);
+/// assert_eq!(String::from_utf8_lossy(&drawn).matches("[-]").count(), 24);
+/// // and so does the changed grid:
+/// drawn.clear();
+/// buffer.back_mut().widen(1).expect("should be able to widen the map!");
+/// buffer.swap_and_draw(&mut drawn).expect("should be able to draw to the buffer!");
+/// assert_eq!(moves(&drawn), 6);
+/// assert_eq!(String::from_utf8_lossy(&drawn).matches("[-]").count(), 29);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DoubleBuffer<T>
 where
     T: Tile + Default,
 {
-    /// Creates `TileMap<T>` from the existing `GridMap<T>` where `T`: `Tile` + `Default`
+    front: TileMap<T>,
+    back: TileMap<T>,
+    drawn: bool,
+}
+
+impl<T> DoubleBuffer<T>
+where
+    T: Tile + Default,
+{
+    /// Creates new `DoubleBuffer<T>` with both buffers being empty `TileMap<T>` of specified size
     ///
-    /// # Examples
+    pub fn new(width: u8, depth: u8) -> Self {
+        Self {
+            front: TileMap::new(width, depth),
+            back: TileMap::new(width, depth),
+            drawn: false,
+        }
+    }
+
+    /// Returns ref to the `front` buffer, which holds the last drawn frame
     ///
-    /// ```
-    /// use cli_tilemap::{Tile, TileMap};
-    /// use crossterm::style::{Stylize, StyledContent};
-    /// use grid_math::{Cell, GridMap};
+    pub fn front(&self) -> &TileMap<T> {
+        &self.front
+    }
+
+    /// Returns ref to the `back` buffer, which holds the next frame
     ///
-    /// #[derive(Debug, Default, PartialEq, Eq)]
-    /// struct Empty;
+    pub fn back(&self) -> &TileMap<T> {
+        &self.back
+    }
+
+    /// Returns mut ref to the `back` buffer, so the next frame can be written to it
     ///
-    /// impl Tile for Empty {
-    ///     fn tile(&self) -> StyledContent<&'static str> {
-    ///         "[-]".dark_grey().bold()
-    ///     }
-    /// }
+    pub fn back_mut(&mut self) -> &mut TileMap<T> {
+        &mut self.back
+    }
+}
+
+impl<T> DoubleBuffer<T>
+where
+    T: Tile + Default + Clone,
+{
+    /// Draws the tiles of the `back` buffer, that differ from the `front` buffer, to the given `stdout`,
+    /// using the `back` buffer `Formatting` rules, and then swaps the buffers
     ///
-    /// let mut gridmap: GridMap<Empty> = GridMap::new(5, 5);
-    /// let target = Cell::new(1, 2);
-    /// gridmap.insert(target, Empty);
-    /// let map: TileMap<Empty> = TileMap::from(gridmap);
-    /// assert_eq!(map.get(&target), Some(&Empty));
-    /// ```
-    fn from(gridmap: GridMap<T>) -> Self {
+    /// The first call draws every tile of the `back` buffer.
+    /// After the swap, the new `back` buffer is synced with the drawn frame,
+    /// so the next frame can be built from the current one
+    ///
+    pub fn swap_and_draw<W: io::Write>(&mut self, stdout: &mut W) -> io::Result<()> {
+        if !self.drawn
+            || self.front.grid() != self.back.grid()
+            || self.front.formatting != self.back.formatting
+        {
+            // separators and spacing are only drawn on the full redraw:
+            self.back
+                .draw_moved(stdout, (0, 0), |_, tile| tile.tile())?;
+        } else {
+            for cell in self.back.grid().cells() {
+                let tile = self.back.tile_at(cell).tile();
+                if self.front.tile_at(cell).tile() != tile {
+                    let (column, row) = self.back.cell_position(cell);
+                    queue!(stdout, MoveTo(column, row), PrintStyledContent(tile))?;
+                }
+            }
+            stdout.flush()?;
+        }
+        self.drawn = true;
+        std::mem::swap(&mut self.front, &mut self.back);
+        self.back.clone_from(&self.front);
+        Ok(())
+    }
+}
+
+impl<T> From<TileMap<T>> for DoubleBuffer<T>
+where
+    T: Tile + Default,
+{
+    /// Creates new `DoubleBuffer<T>` with the given `TileMap<T>` as the `back` buffer,
+    /// and the empty `front` buffer of the same size, so the whole map is drawn on the first swap
+    ///
+    fn from(map: TileMap<T>) -> Self {
         Self {
-            formatting: Formatting::default(),
-            gridmap,
+            front: TileMap::from(map.grid()),
+            back: map,
+            drawn: false,
         }
     }
 }
 
-impl<T> From<(Grid, HashMap<Cell, T>)> for TileMap<T>
+/// `AsyncDraw` represents the drawing of the `TileMap<T>` to the async `stdout` in progress,
+/// holding the rendered map and the number of already written bytes
+///
+/// The `resume` method is cancel-safe: if its future is dropped before completion,
+/// the next call continues writing from the first byte, that was not written yet
+///
+/// # Examples
+///
+/// ```
+/// use cli_tilemap::{AsyncDraw, Tile, TileMap};
+/// use crossterm::style::{Stylize, StyledContent};
+///
+/// #[derive(Default)]
+/// struct Empty;
+///
+/// impl Tile for Empty {
+///     fn tile(&self) -> StyledContent<&'static str> {
+///         "[-]".dark_grey().bold()
+///     }
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let map: TileMap<Empty> = TileMap::new(5, 5);
+///     let mut draw = AsyncDraw::new(&map);
+///     assert!(!draw.is_finished());
+///     let mut buffer: Vec<u8> = Vec::new();
+///     draw.resume(&mut buffer)
+///         .await
+///         .expect("should be able to draw to the buffer!");
+///     assert!(draw.is_finished());
+///     assert_eq!(buffer, map.render_to_buffer());
+/// }
+/// ```
+#[cfg(feature = "async")]
+#[derive(Debug, Clone)]
+pub struct AsyncDraw {
+    buffer: Vec<u8>,
+    written: usize,
+}
+
+#[cfg(feature = "async")]
+impl AsyncDraw {
+    /// Creates new `AsyncDraw`, rendering the given `TileMap<T>` in the same way as the `draw` method does
+    ///
+    pub fn new<T>(map: &TileMap<T>) -> Self
+    where
+        T: Tile + Default,
+    {
+        Self {
+            buffer: map.render_to_buffer(),
+            written: 0,
+        }
+    }
+
+    /// Checks if the whole rendered map is already written
+    ///
+    pub fn is_finished(&self) -> bool {
+        self.written == self.buffer.len()
+    }
+
+    /// Writes the rest of the rendered map to the given async `stdout`, and flushes it
+    ///
+    /// Every `write` call is cancel-safe, and the number of written bytes is saved right after it completes,
+    /// so nothing is written twice or skipped if this future is dropped and the method is called again
+    ///
+    /// # Errors
+    /// Returns `io::Error` if writing fails, or `io::ErrorKind::WriteZero` if the `stdout` accepts no more bytes
+    ///
+    pub async fn resume<W>(&mut self, stdout: &mut W) -> io::Result<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        while !self.is_finished() {
+            let written = stdout.write(&self.buffer[self.written..]).await?;
+            if written == 0 {
+                return Err(io::ErrorKind::WriteZero.into());
+            }
+            self.written += written;
+        }
+        stdout.flush().await
+    }
+}
+
+impl<T> Index<Cell> for TileMap<T>
 where
     T: Tile + Default,
 {
-    /// Creates `TileMap<T>` from the existing `HashMap<Cell, T>` and the given `Grid`
+    type Output = T;
+
+    /// Returns ref to the tile at the given `Cell`, or ref to the default tile if the cell is vacant
     ///
     /// # Panics
-    /// Panics if the given `HashMap<Cell, T>` contains `Cell`s that are not within the given `Grid`
-    /// This panic is a part of `grid-math` crate current state, error handling may change in the future
+    /// Panics if the given `Cell` is not within the inner `Grid`
     ///
     /// # Examples
     ///
     /// ```
     /// use cli_tilemap::{Tile, TileMap};
     /// use crossterm::style::{Stylize, StyledContent};
-    /// use grid_math::{Cell, Grid};
-    /// use std::collections::HashMap;
+    /// use grid_math::Cell;
     ///
     /// #[derive(Debug, Default, PartialEq, Eq)]
-    /// struct Empty;
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
     ///
-    /// impl Tile for Empty {
+    /// impl Tile for Entity {
     ///     fn tile(&self) -> StyledContent<&'static str> {
-    ///         "[-]".dark_grey().bold()
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///         }
     ///     }
     /// }
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let mut hashmap: HashMap<Cell, Empty> = HashMap::new();
-    /// let target = Cell::new(1, 2);
-    /// hashmap.insert(target, Empty);
-    /// let map: TileMap<Empty> = TileMap::from((grid, hashmap));
-    /// assert_eq!(map.get(&target), Some(&Empty));
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map.insert(Cell::new(1, 2), Entity::Hero);
+    /// assert_eq!(map[Cell::new(1, 2)], Entity::Hero);
+    /// assert_eq!(map[Cell::new(3, 3)], Entity::Air);
     /// ```
     ///
     /// ```should_panic
     /// use cli_tilemap::{Tile, TileMap};
     /// use crossterm::style::{Stylize, StyledContent};
-    /// use grid_math::{Cell, Grid};
-    /// use std::collections::HashMap;
+    /// use grid_math::Cell;
     ///
-    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// #[derive(Default)]
     /// struct Empty;
     ///
     /// impl Tile for Empty {
@@ -444,17 +8857,60 @@ where
     ///     }
     /// }
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let mut hashmap: HashMap<Cell, Empty> = HashMap::new();
-    /// let target = Cell::new(7, 1);
-    /// hashmap.insert(target, Empty);
-    /// let map: TileMap<Empty> = TileMap::from((grid, hashmap)); // panic!
+    /// let map: TileMap<Empty> = TileMap::new(5, 5);
+    /// let tile = &map[Cell::new(7, 1)]; // panic!
     /// ```
-    fn from(data: (Grid, HashMap<Cell, T>)) -> Self {
-        Self {
-            formatting: Formatting::default(),
-            gridmap: GridMap::from(data),
-        }
+    fn index(&self, cell: Cell) -> &Self::Output {
+        cell.within_panic(self.grid());
+        self.tile_at(cell)
+    }
+}
+
+impl<T> IndexMut<Cell> for TileMap<T>
+where
+    T: Tile + Default,
+{
+    /// Returns mutable ref to the tile at the given `Cell`, inserting the default tile if the cell is vacant
+    ///
+    /// # Note
+    ///
+    /// The inserted entry is kept even if the tile is left as the default one,
+    /// so the map may contain explicitly inserted default tiles after the indexing.
+    /// Use the `remove` method to clear such cells
+    ///
+    /// # Panics
+    /// Panics if the given `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cli_tilemap::{Tile, TileMap};
+    /// use crossterm::style::{Stylize, StyledContent};
+    /// use grid_math::Cell;
+    ///
+    /// #[derive(Debug, Default, PartialEq, Eq)]
+    /// enum Entity {
+    ///     Hero,
+    ///     #[default]
+    ///     Air,
+    /// }
+    ///
+    /// impl Tile for Entity {
+    ///     fn tile(&self) -> StyledContent<&'static str> {
+    ///         match self {
+    ///             Self::Air => "[-]".dark_grey().bold(),
+    ///             Self::Hero => "[&]".green().bold(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut map: TileMap<Entity> = TileMap::new(5, 5);
+    /// map[Cell::new(1, 2)] = Entity::Hero;
+    /// assert_eq!(map.get(&Cell::new(1, 2)), Some(&Entity::Hero));
+    /// ```
+    fn index_mut(&mut self, cell: Cell) -> &mut Self::Output {
+        cell.within_panic(self.grid());
+        self.gridmap.entry(cell).or_default()
     }
 }
 